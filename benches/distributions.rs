@@ -0,0 +1,84 @@
+// Compares one-at-a-time sampling with the slice-filling paths.
+// Run with `cargo bench --bench distributions`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use unirand::distributions::{Exponential, Normal};
+use unirand::MarsagliaUniRng;
+
+const N: usize = 10_000_000;
+
+fn seeded() -> MarsagliaUniRng {
+    MarsagliaUniRng::from_seed(170)
+}
+
+fn report(name: &str, start: Instant) {
+    let secs = start.elapsed().as_secs_f64();
+    println!(
+        "{:<28} {:>8.2} ms {:>8.2} Msamples/s",
+        name,
+        secs * 1e3,
+        N as f64 / secs / 1e6
+    );
+}
+
+fn main() {
+    let mut buf = vec![0.0f32; N];
+
+    let mut rng = seeded();
+    let start = Instant::now();
+    for x in buf.iter_mut() {
+        *x = rng.uni();
+    }
+    black_box(&buf);
+    report("uniform uni", start);
+
+    let mut rng = seeded();
+    let start = Instant::now();
+    rng.fill_f32(&mut buf);
+    black_box(&buf);
+    report("uniform fill_f32", start);
+
+    let mut rng = seeded();
+    let start = Instant::now();
+    rng.par_fill_f32(&mut buf);
+    black_box(&buf);
+    report("uniform par_fill_f32", start);
+
+    let normal = Normal::new(0.0, 1.0);
+    let mut rng = seeded();
+    let start = Instant::now();
+    for x in buf.iter_mut() {
+        *x = normal.sample(&mut rng);
+    }
+    black_box(&buf);
+    report("normal sample", start);
+
+    let mut rng = seeded();
+    let start = Instant::now();
+    rng.fill_normal(&mut buf, 0.0, 1.0);
+    black_box(&buf);
+    report("normal fill", start);
+
+    let mut rng = seeded();
+    let start = Instant::now();
+    rng.fill_normal_pairs(&mut buf);
+    black_box(&buf);
+    report("normal fill_normal_pairs", start);
+
+    let exponential = Exponential::new(1.5);
+    let mut rng = seeded();
+    let start = Instant::now();
+    for x in buf.iter_mut() {
+        *x = exponential.sample(&mut rng);
+    }
+    black_box(&buf);
+    report("exponential sample", start);
+
+    let mut rng = seeded();
+    let start = Instant::now();
+    rng.fill_exponential(&mut buf, 1.5);
+    black_box(&buf);
+    report("exponential fill", start);
+}
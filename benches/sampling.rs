@@ -0,0 +1,62 @@
+// Times CumulativeSampler on fitness-proportional selection over 10^6 agents:
+// building, sampling, and updating one weight at a time or in batches.
+// Run with `cargo bench --bench sampling`.
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use unirand::sampling::CumulativeSampler;
+use unirand::MarsagliaUniRng;
+
+const AGENTS: usize = 1_000_000;
+const OPS: usize = 1_000_000;
+
+fn report(name: &str, ops: usize, start: Instant) {
+    let secs = start.elapsed().as_secs_f64();
+    println!(
+        "{:<28} {:>8.2} ms {:>8.2} Mops/s",
+        name,
+        secs * 1e3,
+        ops as f64 / secs / 1e6
+    );
+}
+
+fn main() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let weights: Vec<f64> = (0..AGENTS).map(|_| rng.uni() as f64).collect();
+    let updates: Vec<(usize, f64)> = (0..OPS)
+        .map(|_| {
+            (
+                rng.gen_range_u32(0..AGENTS as u32) as usize,
+                rng.uni() as f64,
+            )
+        })
+        .collect();
+
+    let start = Instant::now();
+    let mut sampler = CumulativeSampler::new(&weights);
+    report("build", AGENTS, start);
+
+    let start = Instant::now();
+    for _ in 0..OPS {
+        black_box(sampler.sample(&mut rng));
+    }
+    report("sample", OPS, start);
+
+    let start = Instant::now();
+    for (i, w) in &updates {
+        sampler.set_weight(*i, *w);
+    }
+    report("set_weight", OPS, start);
+
+    let start = Instant::now();
+    for batch in updates.chunks(10_000) {
+        sampler.set_weights(batch);
+    }
+    report("set_weights, batches of 1e4", OPS, start);
+
+    let start = Instant::now();
+    sampler.set_weights(&updates);
+    report("set_weights, one batch", OPS, start);
+    black_box(&sampler);
+}
@@ -0,0 +1,65 @@
+// Checkpoints that trade size against restore speed.
+//
+// A compact checkpoint holds only the four rstart seeds, the number of values
+// drawn since seeding and the indexing; restoring it re-seeds and fast-forwards. A full
+// checkpoint holds the whole state and restores instantly.
+
+use crate::{Indexing, MarsagliaUniRng, Unseeded};
+
+// Kept unboxed so the type works without an allocator.
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, PartialEq)]
+pub enum Checkpoint {
+    Compact {
+        seeds: [i32; 4],
+        draws: u64,
+        indexing: Indexing,
+    },
+    Full(MarsagliaUniRng),
+}
+
+impl MarsagliaUniRng {
+    // Returns the four rstart seeds the generator was built from.
+    pub fn seeds(&self) -> [i32; 4] {
+        self.seeds
+    }
+
+    // Returns the number of values generated since the generator was seeded.
+    pub fn draws(&self) -> u64 {
+        self.draws
+    }
+
+    // Captures the seeds and draw count. Panics if the generator has not been seeded.
+    pub fn checkpoint_compact(&self) -> Checkpoint {
+        if !self.initialized {
+            panic!("checkpoint_compact: generator has not been seeded");
+        }
+        Checkpoint::Compact {
+            seeds: self.seeds,
+            draws: self.draws,
+            indexing: self.indexing,
+        }
+    }
+
+    // Captures the whole state.
+    pub fn checkpoint_full(&self) -> Checkpoint {
+        Checkpoint::Full(self.clone())
+    }
+
+    // Rebuilds the generator a checkpoint was taken from. A compact checkpoint
+    // skips its recorded draws with discard, in O(log draws) time.
+    pub fn restore(checkpoint: &Checkpoint) -> Self {
+        match checkpoint {
+            Checkpoint::Compact {
+                seeds: [i, j, k, l],
+                draws,
+                indexing,
+            } => {
+                let mut rng = Unseeded::with_indexing(*indexing).rstart(*i, *j, *k, *l);
+                rng.discard(*draws);
+                rng
+            }
+            Checkpoint::Full(rng) => rng.clone(),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+// Functions named and behaving like numpy.random's, for porting Python scripts.
+//
+// The generator is passed explicitly where numpy uses its global state, and
+// values are f32 with the generator's 24-bit resolution rather than numpy's
+// f64. The streams differ from numpy's, so only behaviour carries over.
+
+use crate::distributions::Normal;
+use crate::MarsagliaUniRng;
+
+// numpy.random.rand(n): n uniforms in [0, 1).
+pub fn rand(rng: &mut MarsagliaUniRng, n: usize) -> Vec<f32> {
+    (0..n).map(|_| rng.uni()).collect()
+}
+
+// numpy.random.randn(n): n standard normals.
+pub fn randn(rng: &mut MarsagliaUniRng, n: usize) -> Vec<f32> {
+    normal(rng, 0.0, 1.0, n)
+}
+
+// numpy.random.uniform(low, high, n): n uniforms in [low, high).
+// Panics unless low < high and both are finite, as uni_scaled does.
+pub fn uniform(rng: &mut MarsagliaUniRng, low: f32, high: f32, n: usize) -> Vec<f32> {
+    (0..n).map(|_| rng.uni_scaled(low, high)).collect()
+}
+
+// numpy.random.normal(loc, scale, n): n normals with mean loc and standard deviation scale.
+// Panics unless loc is finite and scale is finite and non-negative.
+pub fn normal(rng: &mut MarsagliaUniRng, loc: f32, scale: f32, n: usize) -> Vec<f32> {
+    assert!(
+        loc.is_finite() && scale.is_finite() && scale >= 0.0,
+        "normal: loc = {}, scale = {} -- out of range",
+        loc,
+        scale
+    );
+    // Normal needs scale > 0; numpy allows 0, so scale standard normals.
+    let mut out = vec![0.0; n];
+    Normal::new(0.0, 1.0).fill(rng, &mut out);
+    out.iter_mut().for_each(|x| *x = loc + scale * *x);
+    out
+}
+
+// numpy.random.randint(low, high, n): n integers uniform in [low, high).
+// Panics unless low < high.
+pub fn randint(rng: &mut MarsagliaUniRng, low: i64, high: i64, n: usize) -> Vec<i64> {
+    assert!(
+        low < high,
+        "randint: low = {}, high = {} -- out of range",
+        low,
+        high
+    );
+    // The difference fits in a u64 even when it overflows an i64.
+    let span = high.wrapping_sub(low) as u64;
+    (0..n)
+        .map(|_| low.wrapping_add(rng.gen_range_u64(0..span) as i64))
+        .collect()
+}
+
+// numpy.random.choice(a, n, replace, p): n elements of a, uniformly or with
+// probabilities p. Without replacement, weighted picks are made one at a
+// time from what is left, as numpy does. Panics if a is empty, p has the
+// wrong length, a negative entry or does not sum to 1, or n is more than the
+// elements available without replacement.
+pub fn choice<T: Clone>(
+    rng: &mut MarsagliaUniRng,
+    a: &[T],
+    n: usize,
+    replace: bool,
+    p: Option<&[f64]>,
+) -> Vec<T> {
+    assert!(!a.is_empty(), "choice: a must be non-empty");
+    if let Some(p) = p {
+        assert!(
+            p.len() == a.len(),
+            "choice: p has {} entries for {} elements",
+            p.len(),
+            a.len()
+        );
+        assert!(
+            p.iter().all(|w| w.is_finite() && *w >= 0.0),
+            "choice: probabilities must be non-negative"
+        );
+        // numpy's tolerance for the sum.
+        let total: f64 = p.iter().sum();
+        assert!(
+            (total - 1.0).abs() <= f64::EPSILON.sqrt(),
+            "choice: probabilities sum to {}, not 1",
+            total
+        );
+    }
+    let available = p.map_or(a.len(), |p| p.iter().filter(|w| **w > 0.0).count());
+    assert!(
+        replace || n <= available,
+        "choice: cannot take {} of {} without replacement",
+        n,
+        available
+    );
+    match (p, replace) {
+        (None, true) => (0..n)
+            .map(|_| a[rng.gen_range_u64(0..a.len() as u64) as usize].clone())
+            .collect(),
+        (None, false) => {
+            // Partial Fisher-Yates: the first n indices become a uniform sample.
+            let mut index: Vec<usize> = (0..a.len()).collect();
+            for k in 0..n {
+                let j = rng.gen_range_u64(k as u64..a.len() as u64) as usize;
+                index.swap(k, j);
+            }
+            index[..n].iter().map(|i| a[*i].clone()).collect()
+        }
+        (Some(p), _) => {
+            let mut weights = p.to_vec();
+            (0..n)
+                .map(|_| {
+                    let i = pick_weighted(rng, &weights);
+                    if !replace {
+                        weights[i] = 0.0;
+                    }
+                    a[i].clone()
+                })
+                .collect()
+        }
+    }
+}
+
+// numpy.random.shuffle(x): shuffles in place.
+pub fn shuffle<T>(rng: &mut MarsagliaUniRng, x: &mut [T]) {
+    rng.shuffle(x);
+}
+
+// numpy.random.permutation(n): 0..n in random order.
+pub fn permutation(rng: &mut MarsagliaUniRng, n: usize) -> Vec<usize> {
+    let mut out: Vec<usize> = (0..n).collect();
+    rng.shuffle(&mut out);
+    out
+}
+
+// An index drawn with probability proportional to weights, which are not all zero.
+fn pick_weighted(rng: &mut MarsagliaUniRng, weights: &[f64]) -> usize {
+    let mut target = rng.uni() as f64 * weights.iter().sum::<f64>();
+    for (i, w) in weights.iter().enumerate() {
+        if target < *w {
+            return i;
+        }
+        target -= w;
+    }
+    // Rounding can leave target just above the last weight.
+    weights
+        .iter()
+        .rposition(|w| *w > 0.0)
+        .expect("a positive weight")
+}
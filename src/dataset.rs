@@ -0,0 +1,231 @@
+// Reproducible synthetic dataset generation for the `dataset` subcommand.
+//
+// The schema is a small subset of TOML: an optional `[columns]` header
+// followed by `name = "kind"` lines, one column per line, in output order.
+// Supported kinds are:
+//   sequence            row number starting at 0
+//   uniform             float in [0, 1)
+//   range(a, b)         float in [a, b)
+//   int(a, b)           integer in a..=b
+//   bernoulli(p)        true with probability p
+//   choice(x, y, ...)   one of the listed labels, equally likely
+
+use std::fs;
+use std::io::{self, BufWriter, Write};
+
+use unirand::MarsagliaUniRng;
+
+use crate::{flag_value, int_in, parse_seed};
+
+// The value generator for one column.
+#[derive(Debug)]
+enum Kind {
+    Sequence,
+    Uniform,
+    Range(f32, f32),
+    Int(i64, i64),
+    Bernoulli(f32),
+    Choice(Vec<String>),
+}
+
+#[derive(Debug)]
+struct Column {
+    name: String,
+    kind: Kind,
+}
+
+#[derive(Clone, Copy)]
+enum Format {
+    Csv,
+    Jsonl,
+}
+
+// Runs `unirand dataset` with the arguments following the subcommand.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut schema = None;
+    let mut rows = None;
+    let mut seed = 170;
+    let mut format = Format::Csv;
+    let mut i = 0;
+    while i < args.len() {
+        let value = flag_value(args, i)?;
+        match args[i].as_str() {
+            "--schema" => schema = Some(value),
+            "--rows" => rows = Some(parse_rows(value)?),
+            "--seed" => seed = parse_seed(value)?,
+            "--format" => {
+                format = match value {
+                    "csv" => Format::Csv,
+                    "jsonl" => Format::Jsonl,
+                    _ => return Err(format!("unknown format '{}', expected csv or jsonl", value)),
+                }
+            }
+            other => return Err(format!("unknown dataset option '{}'", other)),
+        }
+        i += 2;
+    }
+    let schema = schema.ok_or("dataset needs --schema")?;
+    let rows = rows.ok_or("dataset needs --rows")?;
+    let text = fs::read_to_string(schema).map_err(|e| format!("{}: {}", schema, e))?;
+    let columns = parse_schema(&text)?;
+
+    let mut rng = MarsagliaUniRng::unseeded().rinit(seed);
+    let stdout = io::stdout();
+    let mut out = BufWriter::new(stdout.lock());
+    write_rows(&mut out, &columns, rows, format, &mut rng).map_err(|e| e.to_string())
+}
+
+// Parses a row count with an optional k, M or G suffix.
+fn parse_rows(text: &str) -> Result<u64, String> {
+    let (digits, scale) = match text.chars().last() {
+        Some('k') | Some('K') => (&text[..text.len() - 1], 1_000),
+        Some('M') => (&text[..text.len() - 1], 1_000_000),
+        Some('G') => (&text[..text.len() - 1], 1_000_000_000),
+        _ => (text, 1),
+    };
+    digits
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(scale))
+        .ok_or_else(|| format!("invalid row count '{}'", text))
+}
+
+fn parse_schema(text: &str) -> Result<Vec<Column>, String> {
+    let mut columns = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or("").trim();
+        if line.is_empty() || line == "[columns]" {
+            continue;
+        }
+        let err = |msg: &str| format!("schema line {}: {}", number + 1, msg);
+        let (name, spec) = line.split_once('=').ok_or_else(|| err("expected name = \"kind\""))?;
+        let spec = spec.trim();
+        let spec = spec
+            .strip_prefix('"')
+            .and_then(|s| s.strip_suffix('"'))
+            .ok_or_else(|| err("kind must be a quoted string"))?;
+        let kind = parse_kind(spec).map_err(|msg| err(&msg))?;
+        columns.push(Column { name: name.trim().to_string(), kind });
+    }
+    if columns.is_empty() {
+        return Err("schema defines no columns".to_string());
+    }
+    Ok(columns)
+}
+
+fn parse_kind(spec: &str) -> Result<Kind, String> {
+    let (name, args) = match spec.split_once('(') {
+        Some((name, rest)) => {
+            let inner = rest.strip_suffix(')').ok_or("missing ')'")?;
+            (name.trim(), inner.split(',').map(str::trim).collect::<Vec<_>>())
+        }
+        None => (spec.trim(), Vec::new()),
+    };
+    let number = |s: &str| s.parse::<f32>().map_err(|_| format!("'{}' is not a number", s));
+    let integer = |s: &str| s.parse::<i64>().map_err(|_| format!("'{}' is not an integer", s));
+    match (name, args.as_slice()) {
+        ("sequence", []) => Ok(Kind::Sequence),
+        ("uniform", []) => Ok(Kind::Uniform),
+        ("range", [a, b]) => {
+            let (a, b) = (number(a)?, number(b)?);
+            if !(a.is_finite() && b.is_finite() && (b - a).is_finite()) {
+                Err(format!("range({}, {}) needs finite bounds and width", a, b))
+            } else if a < b {
+                Ok(Kind::Range(a, b))
+            } else {
+                Err(format!("range({}, {}) is empty", a, b))
+            }
+        }
+        ("int", [a, b]) => {
+            let (a, b) = (integer(a)?, integer(b)?);
+            if a <= b {
+                Ok(Kind::Int(a, b))
+            } else {
+                Err(format!("int({}, {}) is empty", a, b))
+            }
+        }
+        ("bernoulli", [p]) => {
+            let p = number(p)?;
+            if (0.0..=1.0).contains(&p) {
+                Ok(Kind::Bernoulli(p))
+            } else {
+                Err(format!("bernoulli({}) needs p in [0, 1]", p))
+            }
+        }
+        ("choice", labels) if !labels.is_empty() && labels.iter().all(|l| !l.is_empty()) => {
+            Ok(Kind::Choice(labels.iter().map(|l| l.to_string()).collect()))
+        }
+        _ => Err(format!("unknown column kind '{}'", spec)),
+    }
+}
+
+fn write_rows<W: Write>(
+    out: &mut W,
+    columns: &[Column],
+    rows: u64,
+    format: Format,
+    rng: &mut MarsagliaUniRng,
+) -> io::Result<()> {
+    if let Format::Csv = format {
+        let header: Vec<String> = columns.iter().map(|c| csv_field(&c.name)).collect();
+        writeln!(out, "{}", header.join(","))?;
+    }
+    let mut fields = Vec::with_capacity(columns.len());
+    for row in 0..rows {
+        fields.clear();
+        for column in columns {
+            let value = match &column.kind {
+                Kind::Sequence => row.to_string(),
+                Kind::Uniform => rng.uni().to_string(),
+                Kind::Range(a, b) => rng.uni_scaled(*a, *b).to_string(),
+                Kind::Int(a, b) => int_in(rng, *a, *b).to_string(),
+                Kind::Bernoulli(p) => (rng.uni() < *p).to_string(),
+                Kind::Choice(labels) => {
+                    let label = &labels[rng.gen_range_u64(0..labels.len() as u64) as usize];
+                    match format {
+                        Format::Csv => csv_field(label),
+                        Format::Jsonl => json_string(label),
+                    }
+                }
+            };
+            fields.push(value);
+        }
+        match format {
+            Format::Csv => writeln!(out, "{}", fields.join(","))?,
+            Format::Jsonl => {
+                let pairs: Vec<String> = columns
+                    .iter()
+                    .zip(&fields)
+                    .map(|(c, v)| format!("{}:{}", json_string(&c.name), v))
+                    .collect();
+                writeln!(out, "{{{}}}", pairs.join(","))?;
+            }
+        }
+    }
+    out.flush()
+}
+
+// Quotes a CSV field when it contains a separator, quote or newline.
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 2);
+    out.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
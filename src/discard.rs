@@ -0,0 +1,110 @@
+// Skipping ahead in the sequence without generating the values in between.
+//
+// Both parts of the generator are linear in 24-bit integers. With r the number
+// of table slots in use (98 for Indexing::Port, 97 for Indexing::Ranmar) and
+// the lag indices d slots apart, the table sequence obeys
+// x[n] = x[n - r] - x[n - r + d] mod 2^24, so x^N reduced modulo the
+// polynomial x^r + x^d - 1 gives every table entry N steps ahead as a
+// combination of the current ones; square-and-multiply finds it in O(log N)
+// polynomial products. The correction after N steps is c - N * cd mod cm.
+// The result is the state uni would reach after N calls, bit for bit.
+
+use crate::{MarsagliaUniRng, LEN_U, TWO_24};
+
+// Below this many draws, stepping is cheaper than the polynomial arithmetic.
+const STEP_LIMIT: u64 = 4096;
+
+const MASK: u32 = (1 << 24) - 1;
+
+// Multiplies two polynomials of degree below r, modulo x^r + x^d - 1 and 2^32;
+// 2^24 divides 2^32, so reducing the result by MASK gives it mod 2^24.
+fn mul_mod(a: &[u32], b: &[u32], r: usize, d: usize) -> [u32; LEN_U] {
+    let mut product = [0u32; 2 * LEN_U];
+    for (i, x) in a.iter().enumerate().filter(|(_, x)| **x != 0) {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] = product[i + j].wrapping_add(x.wrapping_mul(*y));
+        }
+    }
+    for deg in (r..2 * r - 1).rev() {
+        let c = product[deg];
+        product[deg - r] = product[deg - r].wrapping_add(c);
+        product[deg - r + d] = product[deg - r + d].wrapping_sub(c);
+    }
+    let mut out = [0u32; LEN_U];
+    out[..r].copy_from_slice(&product[..r]);
+    out
+}
+
+// Multiplies a polynomial of degree below r by x, modulo x^r + x^d - 1.
+fn shift_mod(a: &mut [u32], d: usize) {
+    let top = a[a.len() - 1];
+    a.copy_within(..a.len() - 1, 1);
+    a[0] = top;
+    a[d] = a[d].wrapping_sub(top);
+}
+
+impl MarsagliaUniRng {
+    // Advances the generator by n draws, as n calls to uni would, in
+    // O(log n) time. Panics if the generator has not been seeded.
+    pub fn discard(&mut self, n: u64) {
+        if !self.initialized {
+            panic!("discard: generator used before rinit or rstart");
+        }
+        let lowest = self.indexing as usize;
+        let r = LEN_U - lowest;
+        let c = (self.uni_c * TWO_24) as u64;
+        let cd = (self.uni_cd * TWO_24) as u64;
+        let cm = (self.uni_cm * TWO_24) as u64;
+        // States no seeding produces, which the closed forms do not cover, are stepped.
+        let regular = self.uni_ui >= lowest && self.uni_uj >= lowest && c < cm && cd <= cm;
+        if n <= STEP_LIMIT || !regular || self.uni_ui == self.uni_uj {
+            for _ in 0..n {
+                self.uni();
+            }
+            return;
+        }
+
+        // Positions q in 0..r stand for slots lowest + q. ui steps down one position
+        // per draw, so the position k above ui holds the value from k draws ago.
+        let ui = self.uni_ui - lowest;
+        let d = (ui + r - (self.uni_uj - lowest)) % r;
+        let history: [u32; LEN_U] = core::array::from_fn(|k| {
+            // The k-th oldest of the last r values.
+            let slot = lowest + (ui + r - k) % r;
+            if k < r {
+                (self.uni_u[slot] * TWO_24) as u32
+            } else {
+                0
+            }
+        });
+
+        // x^n mod the characteristic polynomial, by binary exponentiation.
+        let mut power = [0u32; LEN_U];
+        power[0] = 1;
+        for bit in (0..64 - n.leading_zeros()).rev() {
+            power = mul_mod(&power[..r], &power[..r], r, d);
+            if (n >> bit) & 1 == 1 {
+                shift_mod(&mut power[..r], d);
+            }
+        }
+
+        // The k-th oldest value after the jump has the coefficients of x^(n + k),
+        // applied to the history.
+        let new_ui = (ui + r - (n % r as u64) as usize) % r;
+        for k in 0..r {
+            let value = power[..r]
+                .iter()
+                .zip(&history[..r])
+                .fold(0u32, |acc, (a, x)| acc.wrapping_add(a.wrapping_mul(*x)));
+            let slot = lowest + (new_ui + r - k) % r;
+            self.uni_u[slot] = (value & MASK) as f32 / TWO_24;
+            shift_mod(&mut power[..r], d);
+        }
+        self.uni_ui = lowest + new_ui;
+        self.uni_uj = lowest + (new_ui + r - d) % r;
+
+        let step = (n % cm) * cd % cm;
+        self.uni_c = ((c + cm - step) % cm) as f32 / TWO_24;
+        self.draws = self.draws.wrapping_add(n);
+    }
+}
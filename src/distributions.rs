@@ -0,0 +1,319 @@
+// Distributions sampled from the generator.
+//
+// Each continuous distribution has a one-at-a-time `sample` and a slice-filling `fill`.
+// `fill` does its parameter setup once per call and uses every value the
+// underlying method produces, so prefer it when drawing many variates.
+// Parameters are validated once, by `try_new` (or `new`, which panics on the
+// same error); sampling a constructed distribution never fails.
+
+use crate::MarsagliaUniRng;
+
+// A distribution parameter rejected by try_new.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParamError {
+    pub distribution: &'static str, // The distribution type, e.g. "Normal".
+    pub parameter: &'static str,    // The first parameter found out of range.
+}
+
+impl core::fmt::Display for ParamError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{}: {} is out of range",
+            self.distribution, self.parameter
+        )
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+// Returns Ok when valid, or the error naming this parameter.
+fn require(
+    valid: bool,
+    distribution: &'static str,
+    parameter: &'static str,
+) -> Result<(), ParamError> {
+    if valid {
+        Ok(())
+    } else {
+        Err(ParamError {
+            distribution,
+            parameter,
+        })
+    }
+}
+
+// Normal distribution, sampled with Marsaglia's polar method.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Normal {
+    mean: f32,
+    std_dev: f32,
+}
+
+impl Normal {
+    // Fails unless mean is finite and std_dev is finite and positive.
+    pub fn try_new(mean: f32, std_dev: f32) -> Result<Self, ParamError> {
+        require(mean.is_finite(), "Normal", "mean")?;
+        require(std_dev.is_finite() && std_dev > 0.0, "Normal", "std_dev")?;
+        Ok(Self { mean, std_dev })
+    }
+
+    // As try_new, panicking on invalid parameters.
+    pub fn new(mean: f32, std_dev: f32) -> Self {
+        Self::try_new(mean, std_dev).unwrap_or_else(|_| {
+            panic!(
+                "Normal::new: mean = {}, std_dev = {} -- out of range",
+                mean, std_dev
+            )
+        })
+    }
+
+    pub fn mean(&self) -> f32 {
+        self.mean
+    }
+
+    pub fn std_dev(&self) -> f32 {
+        self.std_dev
+    }
+
+    // Draws one variate; the second value of the polar pair is discarded.
+    pub fn sample(&self, rng: &mut MarsagliaUniRng) -> f32 {
+        self.mean + self.std_dev * polar_pair(rng).0
+    }
+
+    // Fills buf with variates, using both values of each polar pair.
+    pub fn fill(&self, rng: &mut MarsagliaUniRng, buf: &mut [f32]) {
+        let (mean, std_dev) = (self.mean, self.std_dev);
+        let mut pairs = buf.chunks_exact_mut(2);
+        for pair in &mut pairs {
+            if let [a, b] = pair {
+                let (z0, z1) = polar_pair(rng);
+                *a = mean + std_dev * z0;
+                *b = mean + std_dev * z1;
+            }
+        }
+        if let [last] = pairs.into_remainder() {
+            *last = mean + std_dev * polar_pair(rng).0;
+        }
+    }
+}
+
+// Exponential distribution with rate lambda, sampled by inversion.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Exponential {
+    lambda: f32,
+}
+
+impl Exponential {
+    // Fails unless lambda is finite and positive.
+    pub fn try_new(lambda: f32) -> Result<Self, ParamError> {
+        require(lambda.is_finite() && lambda > 0.0, "Exponential", "lambda")?;
+        Ok(Self { lambda })
+    }
+
+    // As try_new, panicking on invalid parameters.
+    pub fn new(lambda: f32) -> Self {
+        Self::try_new(lambda)
+            .unwrap_or_else(|_| panic!("Exponential::new: lambda = {} -- out of range", lambda))
+    }
+
+    pub fn lambda(&self) -> f32 {
+        self.lambda
+    }
+
+    pub fn sample(&self, rng: &mut MarsagliaUniRng) -> f32 {
+        // 1 - u lies in (0, 1], so the logarithm is always finite.
+        -(1.0 - rng.uni()).ln() / self.lambda
+    }
+
+    pub fn fill(&self, rng: &mut MarsagliaUniRng, buf: &mut [f32]) {
+        let scale = -1.0 / self.lambda;
+        for x in buf.iter_mut() {
+            *x = scale * (1.0 - rng.uni()).ln();
+        }
+    }
+}
+
+// Returns two independent standard normal variates.
+fn polar_pair(rng: &mut MarsagliaUniRng) -> (f32, f32) {
+    loop {
+        let u = 2.0 * rng.uni() - 1.0;
+        let v = 2.0 * rng.uni() - 1.0;
+        let s = u * u + v * v;
+        if s > 0.0 && s < 1.0 {
+            let factor = (-2.0 * s.ln() / s).sqrt();
+            return (u * factor, v * factor);
+        }
+    }
+}
+
+// Maps two uniforms in [0, 1) to two independent standard normal variates.
+fn box_muller(u1: f32, u2: f32) -> (f32, f32) {
+    // 1 - u1 lies in (0, 1], so the logarithm is always finite.
+    let radius = (-2.0 * (1.0 - u1).ln()).sqrt();
+    let (sin, cos) = (core::f32::consts::TAU * u2).sin_cos();
+    (radius * cos, radius * sin)
+}
+
+impl MarsagliaUniRng {
+    // Fills buf with normal variates; see Normal::fill. Unlike Normal::new this
+    // never panics: parameters Normal::new would reject give NaN or infinite values.
+    pub fn fill_normal(&mut self, buf: &mut [f32], mean: f32, std_dev: f32) {
+        Normal { mean, std_dev }.fill(self, buf);
+    }
+
+    // Fills buf with standard normal variates by Box-Muller. Uniforms are drawn in
+    // bulk into buf first and then transformed in place two at a time; with no
+    // rejection step the loop is branch-free and vectorises well.
+    pub fn fill_normal_pairs(&mut self, buf: &mut [f32]) {
+        self.fill_f32(buf);
+        let mut pairs = buf.chunks_exact_mut(2);
+        for pair in &mut pairs {
+            if let [a, b] = pair {
+                (*a, *b) = box_muller(*a, *b);
+            }
+        }
+        if let [last] = pairs.into_remainder() {
+            *last = box_muller(*last, self.uni()).0;
+        }
+    }
+
+    // Fills buf with exponential variates of rate lambda; see Exponential::fill.
+    // Like fill_normal it never panics, giving NaN or infinite values for bad lambda.
+    pub fn fill_exponential(&mut self, buf: &mut [f32], lambda: f32) {
+        Exponential { lambda }.fill(self, buf);
+    }
+}
+
+// Log-normal distribution: exp of a normal with the given mu and sigma.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LogNormal {
+    normal: Normal,
+}
+
+impl LogNormal {
+    // Fails unless mu is finite and sigma is finite and positive.
+    pub fn try_new(mu: f32, sigma: f32) -> Result<Self, ParamError> {
+        require(mu.is_finite(), "LogNormal", "mu")?;
+        require(sigma.is_finite() && sigma > 0.0, "LogNormal", "sigma")?;
+        Ok(Self {
+            normal: Normal {
+                mean: mu,
+                std_dev: sigma,
+            },
+        })
+    }
+
+    // As try_new, panicking on invalid parameters.
+    pub fn new(mu: f32, sigma: f32) -> Self {
+        Self::try_new(mu, sigma).unwrap_or_else(|_| {
+            panic!(
+                "LogNormal::new: mu = {}, sigma = {} -- out of range",
+                mu, sigma
+            )
+        })
+    }
+
+    pub fn sample(&self, rng: &mut MarsagliaUniRng) -> f32 {
+        self.normal.sample(rng).exp()
+    }
+
+    pub fn fill(&self, rng: &mut MarsagliaUniRng, buf: &mut [f32]) {
+        self.normal.fill(rng, buf);
+        for x in buf.iter_mut() {
+            *x = x.exp();
+        }
+    }
+}
+
+// Zipf distribution over ranks 1..=n, with P(k) proportional to 1 / k^s.
+// Sampled by binary search of a precomputed cumulative table.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Zipf {
+    cdf: Vec<f64>,
+}
+
+impl Zipf {
+    // Fails unless n >= 1 and s is finite and non-negative.
+    pub fn try_new(n: usize, s: f64) -> Result<Self, ParamError> {
+        require(n > 0, "Zipf", "n")?;
+        require(s.is_finite() && s >= 0.0, "Zipf", "s")?;
+        let mut total = 0.0;
+        let mut cdf: Vec<f64> = (1..=n)
+            .map(|k| {
+                total += (k as f64).powf(-s);
+                total
+            })
+            .collect();
+        for c in cdf.iter_mut() {
+            *c /= total;
+        }
+        Ok(Self { cdf })
+    }
+
+    // As try_new, panicking on invalid parameters.
+    pub fn new(n: usize, s: f64) -> Self {
+        Self::try_new(n, s)
+            .unwrap_or_else(|_| panic!("Zipf::new: n = {}, s = {} -- out of range", n, s))
+    }
+
+    pub fn n(&self) -> usize {
+        self.cdf.len()
+    }
+
+    // Returns a rank in 1..=n.
+    pub fn sample(&self, rng: &mut MarsagliaUniRng) -> usize {
+        let u = rng.uni() as f64;
+        self.cdf
+            .partition_point(|c| *c <= u)
+            .min(self.cdf.len() - 1)
+            + 1
+    }
+}
+
+// Gumbel (type I extreme value) distribution with location mu and scale beta.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Gumbel {
+    mu: f32,
+    beta: f32,
+}
+
+impl Gumbel {
+    // Fails unless mu is finite and beta is finite and positive.
+    pub fn try_new(mu: f32, beta: f32) -> Result<Self, ParamError> {
+        require(mu.is_finite(), "Gumbel", "mu")?;
+        require(beta.is_finite() && beta > 0.0, "Gumbel", "beta")?;
+        Ok(Self { mu, beta })
+    }
+
+    // As try_new, panicking on invalid parameters.
+    pub fn new(mu: f32, beta: f32) -> Self {
+        Self::try_new(mu, beta)
+            .unwrap_or_else(|_| panic!("Gumbel::new: mu = {}, beta = {} -- out of range", mu, beta))
+    }
+
+    pub fn mu(&self) -> f32 {
+        self.mu
+    }
+
+    pub fn beta(&self) -> f32 {
+        self.beta
+    }
+
+    pub fn sample(&self, rng: &mut MarsagliaUniRng) -> f32 {
+        self.mu + self.beta * standard_gumbel(rng) as f32
+    }
+
+    pub fn fill(&self, rng: &mut MarsagliaUniRng, buf: &mut [f32]) {
+        for x in buf.iter_mut() {
+            *x = self.sample(rng);
+        }
+    }
+}
+
+// -ln(-ln u) with u at the midpoint of a 24-bit cell, so u is never 0 or 1
+// and the result is always finite.
+pub(crate) fn standard_gumbel(rng: &mut MarsagliaUniRng) -> f64 {
+    let u = rng.uni() as f64 + 0.5 / 16_777_216.0;
+    -(-u.ln()).ln()
+}
@@ -0,0 +1,43 @@
+use crate::MarsagliaUniRng;
+
+// Byte order of the crate's binary formats. Every format defaults to Little.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Endian {
+    #[default]
+    Little,
+    Big,
+}
+
+impl Endian {
+// Reorders little-endian bytes into this byte order (and back, as the swap is its own inverse).
+    pub(crate) fn order<const N: usize>(self, mut bytes: [u8; N]) -> [u8; N] {
+        if self == Endian::Big {
+            bytes.reverse();
+        }
+        bytes
+    }
+}
+
+impl MarsagliaUniRng {
+// Fills dest with raw bytes: each draw gives the three bytes of its 24-bit numerator,
+// least significant first, so the bytes are the same on every platform and match
+// RngReader::new. A final partial chunk takes the leading bytes of one more draw.
+    pub fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.fill_bytes_endian(dest, Endian::Little);
+    }
+
+// As fill_bytes, with each draw's three bytes in the given order, matching RngReader::with_endian.
+    pub fn fill_bytes_endian(&mut self, dest: &mut [u8], endian: Endian) {
+        let mut chunks = dest.chunks_exact_mut(3);
+        for chunk in &mut chunks {
+            let [b0, b1, b2, _] = self.next_word().to_le_bytes();
+            chunk.copy_from_slice(&endian.order([b0, b1, b2]));
+        }
+        let rest = chunks.into_remainder();
+        if !rest.is_empty() {
+            let [b0, b1, b2, _] = self.next_word().to_le_bytes();
+            let bytes = endian.order([b0, b1, b2]);
+            rest.copy_from_slice(&bytes[..rest.len()]);
+        }
+    }
+}
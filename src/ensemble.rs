@@ -0,0 +1,59 @@
+// Runs one simulation per seed and keeps each result alongside the seed that produced it.
+
+use std::{panic, thread};
+
+use crate::MarsagliaUniRng;
+
+// The output of one ensemble member.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SeededRun<T> {
+    pub seed: i32,
+    pub output: T,
+}
+
+pub struct Ensemble;
+
+impl Ensemble {
+    // Calls f with a fresh generator for each seed, in order.
+    // Panics if a seed is outside 0..=900_000_000, as rinit does.
+    pub fn run<T, F>(seeds: &[i32], mut f: F) -> Vec<SeededRun<T>>
+    where
+        F: FnMut(&mut MarsagliaUniRng) -> T,
+    {
+        seeds
+            .iter()
+            .map(|&seed| SeededRun {
+                seed,
+                output: f(&mut seeded(seed)),
+            })
+            .collect()
+    }
+
+    // As run, spread over the given number of threads. Each member depends only
+    // on its seed, so the results are identical to run's whatever the thread count.
+    pub fn run_parallel<T, F>(seeds: &[i32], threads: usize, f: F) -> Vec<SeededRun<T>>
+    where
+        T: Send,
+        F: Fn(&mut MarsagliaUniRng) -> T + Sync,
+    {
+        if seeds.is_empty() {
+            return Vec::new();
+        }
+        let chunk = seeds.len().div_ceil(threads.max(1));
+        let f = &f;
+        thread::scope(|scope| {
+            let workers: Vec<_> = seeds
+                .chunks(chunk)
+                .map(|part| scope.spawn(move || Self::run(part, f)))
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| worker.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+                .collect()
+        })
+    }
+}
+
+fn seeded(seed: i32) -> MarsagliaUniRng {
+    MarsagliaUniRng::unseeded().rinit(seed)
+}
@@ -0,0 +1,98 @@
+// The crate-wide error type.
+//
+// Each fallible API keeps its own precise error type, such as RangeError, and
+// converts into Error, so callers mixing several APIs can use `?` with a single
+// error type. The wrapped error is the source, and Error's Display names the
+// failing area before the detail. New variants may be added in minor releases.
+
+#[cfg(feature = "std")]
+use crate::distributions::ParamError;
+use crate::{
+    InvariantViolation, RangeError, RejectionLimitError, SeedError, SelfTestError, StateError,
+};
+
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Error {
+    Seed(SeedError),                     // A seed was out of range.
+    Range(RangeError),                   // A range argument was rejected.
+    RejectionLimit(RejectionLimitError), // A rejection sampler reached its draw limit.
+    Invariant(InvariantViolation),       // A generator state is not well formed.
+    SelfTest(SelfTestError),             // The build did not reproduce the reference stream.
+    State(StateError),                   // A state snapshot could not be restored.
+    #[cfg(feature = "std")]
+    Param(ParamError), // A distribution parameter was rejected.
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Error::Seed(e) => write!(f, "{}", e),
+            Error::Range(e) => write!(f, "invalid range: {}", e),
+            Error::RejectionLimit(e) => write!(f, "sampling failed: {}", e),
+            Error::Invariant(e) => write!(f, "invalid generator state: {}", e),
+            Error::SelfTest(e) => write!(f, "{}", e),
+            Error::State(e) => write!(f, "{}", e),
+            #[cfg(feature = "std")]
+            Error::Param(e) => write!(f, "invalid distribution parameter: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Seed(e) => Some(e),
+            Error::Range(e) => Some(e),
+            Error::RejectionLimit(e) => Some(e),
+            Error::Invariant(e) => Some(e),
+            Error::SelfTest(e) => Some(e),
+            Error::State(e) => Some(e),
+            Error::Param(e) => Some(e),
+        }
+    }
+}
+
+impl From<SeedError> for Error {
+    fn from(e: SeedError) -> Self {
+        Error::Seed(e)
+    }
+}
+
+impl From<RangeError> for Error {
+    fn from(e: RangeError) -> Self {
+        Error::Range(e)
+    }
+}
+
+impl From<RejectionLimitError> for Error {
+    fn from(e: RejectionLimitError) -> Self {
+        Error::RejectionLimit(e)
+    }
+}
+
+impl From<InvariantViolation> for Error {
+    fn from(e: InvariantViolation) -> Self {
+        Error::Invariant(e)
+    }
+}
+
+impl From<SelfTestError> for Error {
+    fn from(e: SelfTestError) -> Self {
+        Error::SelfTest(e)
+    }
+}
+
+impl From<StateError> for Error {
+    fn from(e: StateError) -> Self {
+        Error::State(e)
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<ParamError> for Error {
+    fn from(e: ParamError) -> Self {
+        Error::Param(e)
+    }
+}
@@ -0,0 +1,79 @@
+// Independent generators from one master seed, one per stream id.
+//
+// Each id is mapped to a distinct set of rstart seeds by a permutation of the
+// whole seed space keyed by the master seed, so two ids of one family never
+// share a table, however many streams are used. The permutation is a
+// four-round Feistel network on 30-bit values with SplitMix64 round
+// functions, cycle-walked down to the MAX_STREAMS valid seed sets. Unlike
+// seed + thread_id schemes, neighbouring ids get unrelated seeds.
+
+use crate::generator::mix64;
+use crate::MarsagliaUniRng;
+
+// The number of valid rstart seed sets: i, j and k in 1..=178, not all 1, by
+// l in 0..=168.
+pub const MAX_STREAMS: u64 = (178 * 178 * 178 - 1) * 169;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct StreamFamily {
+    master_seed: u64,
+    key: u64,
+}
+
+impl StreamFamily {
+    pub fn new(master_seed: u64) -> Self {
+        Self {
+            master_seed,
+            key: mix64(master_seed),
+        }
+    }
+
+    pub fn master_seed(&self) -> u64 {
+        self.master_seed
+    }
+
+    // Returns the seeded generator for stream id. Panics if id is not below
+    // MAX_STREAMS.
+    pub fn stream(&self, id: u64) -> MarsagliaUniRng {
+        let [i, j, k, l] = self.stream_seeds(id);
+        MarsagliaUniRng::unseeded().rstart(i, j, k, l)
+    }
+
+    // Returns the rstart seeds of stream id, distinct for every id. Panics if
+    // id is not below MAX_STREAMS.
+    pub fn stream_seeds(&self, id: u64) -> [i32; 4] {
+        assert!(
+            id < MAX_STREAMS,
+            "StreamFamily::stream: id = {} -- out of range",
+            id
+        );
+        // Applying the permutation until the value falls below MAX_STREAMS
+        // restricts it to a permutation of 0..MAX_STREAMS.
+        let mut x = id;
+        loop {
+            x = self.feistel(x);
+            if x < MAX_STREAMS {
+                break;
+            }
+        }
+        // Index 0 of the i, j, k digits would be 1 1 1, which rstart forbids.
+        let l = x % 169;
+        let ijk = x / 169 + 1;
+        [
+            (1 + ijk / (178 * 178)) as i32,
+            (1 + ijk / 178 % 178) as i32,
+            (1 + ijk % 178) as i32,
+            l as i32,
+        ]
+    }
+
+    // A bijection on 0..2^30.
+    fn feistel(&self, x: u64) -> u64 {
+        let (mut left, mut right) = (x >> 15, x & 0x7fff);
+        for round in 0..4u64 {
+            let f = mix64(self.key ^ (round << 32) ^ right) & 0x7fff;
+            (left, right) = (right, left ^ f);
+        }
+        (left << 15) | right
+    }
+}
@@ -0,0 +1,7 @@
+// Binary and text formats for recording, exporting and exchanging streams.
+
+#[cfg(feature = "std")]
+pub mod archive;
+#[cfg(feature = "std")]
+pub mod export;
+pub mod table;
@@ -0,0 +1,140 @@
+// Archival record of a run's outputs, verifiable against regeneration.
+//
+// Layout; every integer after the marker byte is in the byte order it names,
+// little-endian by default:
+//
+//   bytes 0..4     magic "UNRA"
+//   byte  4        byte order marker, b'L' or b'B'
+//   bytes 5..7     format version (1)
+//   bytes 7..23    the four rstart seeds, i32 each
+//   bytes 23..31   draws made after seeding before the first archived value, u64
+//   bytes 31..39   number of archived values, u64
+//   then           one 3-byte delta per value: (value - previous) mod 2^24,
+//                  where value is the 24-bit numerator and previous starts at 0
+//
+// The body has no per-value framing, so a general-purpose compressor run over
+// the archive only sees the value bits, and other languages can read it with a
+// few lines of code. The header does not record indexing, so only
+// Indexing::Port streams can be archived.
+
+use std::io::{self, Read, Write};
+
+use crate::{Endian, Indexing, MarsagliaUniRng};
+
+const MAGIC: [u8; 4] = *b"UNRA";
+pub const ARCHIVE_VERSION: u16 = 1;
+const MASK: u32 = (1 << 24) - 1;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Archive {
+    pub version: u16,
+    pub endian: Endian,   // Byte order the archive was written in.
+    pub seeds: [i32; 4],  // rstart seeds of the archived generator.
+    pub start_draw: u64,  // Draws made before the first archived value.
+    pub values: Vec<u32>, // The archived outputs as 24-bit numerators.
+}
+
+// Draws count values from rng and writes them as a little-endian archive.
+pub fn write_archive<W: Write>(
+    out: &mut W,
+    rng: &mut MarsagliaUniRng,
+    count: u64,
+) -> io::Result<()> {
+    write_archive_endian(out, rng, count, Endian::Little)
+}
+
+// Draws count values from rng and writes them as an archive in the given byte order.
+// Fails with InvalidInput, writing nothing, unless rng uses Indexing::Port.
+pub fn write_archive_endian<W: Write>(
+    out: &mut W,
+    rng: &mut MarsagliaUniRng,
+    count: u64,
+    endian: Endian,
+) -> io::Result<()> {
+    if rng.indexing() != Indexing::Port {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "archives record Indexing::Port streams only",
+        ));
+    }
+    out.write_all(&MAGIC)?;
+    out.write_all(&[marker(endian)])?;
+    out.write_all(&endian.order(ARCHIVE_VERSION.to_le_bytes()))?;
+    for seed in rng.seeds() {
+        out.write_all(&endian.order(seed.to_le_bytes()))?;
+    }
+    out.write_all(&endian.order(rng.draws().to_le_bytes()))?;
+    out.write_all(&endian.order(count.to_le_bytes()))?;
+    let mut previous = 0;
+    for _ in 0..count {
+        let value = rng.next_word();
+        let [b0, b1, b2, _] = (value.wrapping_sub(previous) & MASK).to_le_bytes();
+        out.write_all(&endian.order([b0, b1, b2]))?;
+        previous = value;
+    }
+    out.flush()
+}
+
+fn marker(endian: Endian) -> u8 {
+    match endian {
+        Endian::Little => b'L',
+        Endian::Big => b'B',
+    }
+}
+
+// Reads an archive written in either byte order.
+pub fn read_archive<R: Read>(input: &mut R) -> io::Result<Archive> {
+    let mut header = [0u8; 39];
+    input.read_exact(&mut header)?;
+    if header[0..4] != MAGIC {
+        return Err(invalid("not a unirand archive"));
+    }
+    let endian = match header[4] {
+        b'L' => Endian::Little,
+        b'B' => Endian::Big,
+        _ => return Err(invalid("unknown archive byte order")),
+    };
+    let field = |at: usize| -> [u8; 8] {
+        endian.order(header[at..at + 8].try_into().expect("8-byte slice"))
+    };
+    let version = u16::from_le_bytes(endian.order([header[5], header[6]]));
+    if version != ARCHIVE_VERSION {
+        return Err(invalid("unsupported archive version"));
+    }
+    let mut seeds = [0; 4];
+    for (seed, bytes) in seeds.iter_mut().zip(header[7..23].chunks_exact(4)) {
+        *seed = i32::from_le_bytes(endian.order(bytes.try_into().expect("4-byte chunk")));
+    }
+    let start_draw = u64::from_le_bytes(field(23));
+    let count = u64::from_le_bytes(field(31));
+    let mut values = Vec::new();
+    let mut previous = 0u32;
+    let mut delta = [0u8; 3];
+    for _ in 0..count {
+        input.read_exact(&mut delta)?;
+        let [b0, b1, b2] = endian.order(delta);
+        previous = previous.wrapping_add(u32::from_le_bytes([b0, b1, b2, 0])) & MASK;
+        values.push(previous);
+    }
+    Ok(Archive {
+        version,
+        endian,
+        seeds,
+        start_draw,
+        values,
+    })
+}
+
+impl Archive {
+    // Regenerates the run from the recorded seeds and checks every archived value.
+    pub fn verify(&self) -> bool {
+        let [i, j, k, l] = self.seeds;
+        let mut rng = MarsagliaUniRng::unseeded().rstart(i, j, k, l);
+        rng.discard(self.start_draw);
+        self.values.iter().all(|value| rng.next_word() == *value)
+    }
+}
+
+fn invalid(msg: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.to_string())
+}
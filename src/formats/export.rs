@@ -0,0 +1,74 @@
+// CSV exports for eyeballing sampler output in an external plotting tool.
+//
+// Each function takes the sampler as a closure, so any distribution built on
+// the generator can be checked the same way, e.g. `|| rng.uni()`.
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+// Draws n samples and writes a histogram with `bin_start,bin_end,count,density` rows.
+// The bins evenly span the smallest to the largest sample drawn.
+pub fn histogram_csv<P: AsRef<Path>>(
+    mut dist: impl FnMut() -> f32,
+    n: usize,
+    bins: usize,
+    path: P,
+) -> io::Result<()> {
+    if n == 0 || bins == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "histogram_csv: n and bins must be positive",
+        ));
+    }
+    let samples: Vec<f32> = (0..n).map(|_| dist()).collect();
+    let lo = samples.iter().copied().fold(f32::INFINITY, f32::min);
+    let hi = samples.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    if !lo.is_finite() || !hi.is_finite() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "histogram_csv: sampler produced a non-finite value",
+        ));
+    }
+    let width = if hi > lo {
+        (hi as f64 - lo as f64) / bins as f64
+    } else {
+        1.0
+    };
+    let mut counts = vec![0u64; bins];
+    for x in &samples {
+        let bin = ((*x as f64 - lo as f64) / width) as usize;
+        counts[bin.min(bins - 1)] += 1;
+    }
+    let mut out = BufWriter::new(File::create(path)?);
+    writeln!(out, "bin_start,bin_end,count,density")?;
+    for (i, count) in counts.iter().enumerate() {
+        let start = lo as f64 + i as f64 * width;
+        let density = *count as f64 / (n as f64 * width);
+        writeln!(out, "{},{},{},{}", start, start + width, count, density)?;
+    }
+    out.flush()
+}
+
+// Draws n + lag samples and writes `x,y` rows pairing each sample with the one lag draws later.
+// Structure in the resulting scatter plot points at serial correlation.
+pub fn lag_scatter_csv<P: AsRef<Path>>(
+    mut dist: impl FnMut() -> f32,
+    n: usize,
+    lag: usize,
+    path: P,
+) -> io::Result<()> {
+    if lag == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "lag_scatter_csv: lag must be positive",
+        ));
+    }
+    let samples: Vec<f32> = (0..n + lag).map(|_| dist()).collect();
+    let mut out = BufWriter::new(File::create(path)?);
+    writeln!(out, "x,y")?;
+    for (x, y) in samples.iter().zip(&samples[lag..]) {
+        writeln!(out, "{},{}", x, y)?;
+    }
+    out.flush()
+}
@@ -0,0 +1,101 @@
+// Integer export of the generator state, for continuing a stream elsewhere (e.g. a GPU kernel).
+//
+// Every value the generator holds is a multiple of 2^-24, so the state is
+// exported exactly as 24-bit numerators in EXPORT_TABLE_LEN u32 words:
+//
+//   words 0..=97   table u[0..=97]
+//   word  98       correction c
+//   word  99       correction delta cd (7654321)
+//   word  100      correction modulus cm (16777213)
+//   word  101      index ui (0..=97)
+//   word  102      index uj (0..=97)
+//
+// One step, in integer arithmetic with 2^24 = 16777216:
+//
+//   x = u[ui] - u[uj];  if x < 0 { x += 2^24 }
+//   u[ui] = x
+//   ui = (ui == 0) ? 97 : ui - 1;  uj = (uj == 0) ? 97 : uj - 1
+//   c = c - cd;  if c < 0 { c += cm }
+//   x = x - c;  if x < 0 { x += 2^24 }
+//   output x, i.e. the value x / 2^24
+//
+// step_exported_table is this step written out in Rust; a kernel port that
+// matches it continues the identical sequence. The step is that of
+// Indexing::Port, so only Port generators are exported.
+
+use crate::{Endian, Indexing, MarsagliaUniRng, LEN_U, TWO_24};
+
+pub const EXPORT_TABLE_LEN: usize = LEN_U + 5;
+
+const ONE: u32 = 1 << 24;
+const C: usize = LEN_U;
+const CD: usize = LEN_U + 1;
+const CM: usize = LEN_U + 2;
+const UI: usize = LEN_U + 3;
+const UJ: usize = LEN_U + 4;
+
+impl MarsagliaUniRng {
+    // Exports the current state in the layout described above.
+    // Panics if the generator does not use Indexing::Port.
+    pub fn export_table(&self) -> [u32; EXPORT_TABLE_LEN] {
+        assert!(
+            self.indexing == Indexing::Port,
+            "export_table: the table format describes Indexing::Port only"
+        );
+        let numerator = |x: f32| (x * TWO_24) as u32;
+        let mut table = [0; EXPORT_TABLE_LEN];
+        for (word, u) in table.iter_mut().zip(&self.uni_u) {
+            *word = numerator(*u);
+        }
+        table[C] = numerator(self.uni_c);
+        table[CD] = numerator(self.uni_cd);
+        table[CM] = numerator(self.uni_cm);
+        table[UI] = self.uni_ui as u32;
+        table[UJ] = self.uni_uj as u32;
+        table
+    }
+}
+
+// Exports the state of a generator freshly initialised with rinit(seed).
+pub fn export_init_table(seed: i32) -> [u32; EXPORT_TABLE_LEN] {
+    MarsagliaUniRng::unseeded().rinit(seed).export_table()
+}
+
+// Serialises an exported table as consecutive 4-byte words in the given byte order.
+pub fn export_table_bytes(
+    table: &[u32; EXPORT_TABLE_LEN],
+    endian: Endian,
+) -> [u8; 4 * EXPORT_TABLE_LEN] {
+    let mut bytes = [0; 4 * EXPORT_TABLE_LEN];
+    for (chunk, word) in bytes.chunks_exact_mut(4).zip(table) {
+        chunk.copy_from_slice(&endian.order(word.to_le_bytes()));
+    }
+    bytes
+}
+
+// Advances an exported table by one step and returns the output numerator.
+// Panics if the indices in the table are not in 0..=97.
+pub fn step_exported_table(table: &mut [u32; EXPORT_TABLE_LEN]) -> u32 {
+    let (ui, uj) = (table[UI] as usize, table[UJ] as usize);
+    assert!(
+        ui < LEN_U && uj < LEN_U,
+        "step_exported_table: index out of range"
+    );
+    let x = (table[ui] + ONE - table[uj]) % ONE;
+    table[ui] = x;
+    table[UI] = if ui == 0 { 97 } else { ui as u32 - 1 };
+    table[UJ] = if uj == 0 { 97 } else { uj as u32 - 1 };
+    table[C] = if table[C] >= table[CD] {
+        table[C] - table[CD]
+    } else {
+        table[C] + table[CM] - table[CD]
+    };
+    (x + ONE - table[C]) % ONE
+}
+
+// Checks that stepping the exported table of seed reproduces the first draws of rinit(seed).
+pub fn verify_exported_table(seed: i32, draws: usize) -> bool {
+    let mut table = export_init_table(seed);
+    let mut rng = MarsagliaUniRng::unseeded().rinit(seed);
+    (0..draws).all(|_| step_exported_table(&mut table) == rng.next_word())
+}
@@ -0,0 +1,461 @@
+// The generator itself: its state, seeding, the core step and state identity.
+
+use core::hash::{Hash, Hasher};
+use core::ops::{Range, RangeInclusive};
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::hasher::Fnv1a;
+use crate::seed::decompose;
+use crate::{Indexing, SeedError};
+
+pub(crate) const LEN_U: usize = 98; // Constant defining the length of the random values array.
+
+pub(crate) const TWO_24: f32 = 16777216.0; // Scale of the 24-bit output fraction.
+
+#[cold]
+fn not_initialized() -> ! {
+    panic!("uni: generator used before rinit or rstart");
+}
+
+// The SplitMix64 finalising mixer, spreading every input bit across the output.
+pub(crate) fn mix64(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+// Validates and decomposes a single rinit seed into the four rstart seeds, panicking on a bad seed.
+pub(crate) fn rinit_seeds(ijkl: i32) -> (i32, i32, i32, i32) {
+    match decompose(ijkl) {
+        Ok(seeds) => seeds,
+        Err(SeedError::Ijkl(v)) => panic!("rinit: ijkl = {} -- out of range", v),
+        Err(SeedError::I(v)) => panic!("rinit: i = {} -- out of range", v),
+        Err(SeedError::J(v)) => panic!("rinit: j = {} -- out of range", v),
+        Err(SeedError::K(v)) => panic!("rinit: k = {} -- out of range", v),
+        Err(SeedError::L(v)) => panic!("rinit: l = {} -- out of range", v),
+        Err(SeedError::AllOnes) => panic!("rinit: 1 1 1 not allowed for 1st 3 seeds"),
+    }
+}
+
+// A struct representing Marsaglia's Universal Random Number Generator.
+#[derive(Clone, Debug)]
+pub struct MarsagliaUniRng {
+    pub(crate) uni_u: [f32; LEN_U], // Array holding the recent random numbers.
+    pub(crate) uni_c: f32, 		// Correction to avoid periodicity.
+    pub(crate) uni_cd: f32, 		// Correction delta value.
+    pub(crate) uni_cm: f32,		// Correction modulus.
+    pub(crate) uni_ui: usize,		// Current position in the random values array.
+    pub(crate) uni_uj: usize,
+    pub(crate) initialized: bool,	// Set once rinit or rstart has filled the table.
+    pub(crate) seeds: [i32; 4],	// The four rstart seeds the table was built from.
+    pub(crate) draws: u64,		// Values generated since seeding.
+    pub(crate) indexing: Indexing,	// Which slots ui and uj cycle through.
+}
+
+impl MarsagliaUniRng {
+// The zeroed table every seeding path starts from; uni panics on it until seeded.
+    pub(crate) fn blank(indexing: Indexing) -> Self {
+        Self {
+            uni_u: [0.0; LEN_U],
+            uni_c: 0.0,
+            uni_cd: 0.0,
+            uni_cm: 0.0,
+            uni_ui: 0,
+            uni_uj: 0,
+            initialized: false,
+            seeds: [0; 4],
+            draws: 0,
+            indexing,
+        }
+    }
+
+// Starts a new generator, which must be seeded before it gives values:
+// MarsagliaUniRng::new().rinit(170).
+    #[cfg(not(feature = "legacy"))]
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new() -> Unseeded {
+        Unseeded::new()
+    }
+
+// The pre-Unseeded constructor: an unseeded generator whose uni panics until
+// rinit or rstart is called.
+    #[cfg(feature = "legacy")]
+    pub fn new() -> Self {
+        Self::blank(Indexing::Port)
+    }
+// Generate a new random float value between 0 and 1
+// Panics if the generator has not been seeded with rinit or rstart.
+pub fn uni(&mut self) -> f32 {
+    if !self.initialized {
+        not_initialized();
+    }
+// Clamping is a no-op for every reachable state but lets the compiler drop the
+// bounds checks, so once seeded uni has no path that can panic.
+    let ui = self.uni_ui.min(LEN_U - 1);
+    let uj = self.uni_uj.min(LEN_U - 1);
+    let mut luni = self.uni_u[ui] - self.uni_u[uj];
+    if luni < 0.0 {
+        luni += 1.0;
+    }
+    self.uni_u[ui] = luni;
+    
+// Adjust indices for the next random number generation, wrapping below the lowest slot in use.
+    let lowest = self.indexing as usize;
+    if self.uni_ui <= lowest {
+        self.uni_ui = 97;
+    } else {
+        self.uni_ui -= 1;
+    }
+    if self.uni_uj <= lowest {
+        self.uni_uj = 97;
+    } else {
+        self.uni_uj -= 1;
+    }
+
+    self.uni_c -= self.uni_cd;
+    if self.uni_c < 0.0 {
+        self.uni_c += self.uni_cm;
+    }
+
+    luni -= self.uni_c;
+    if luni < 0.0 {
+        luni += 1.0;
+    }
+    self.draws = self.draws.wrapping_add(1);
+    luni
+}
+
+// Initialises the random values array using four seeds.
+    pub fn rstart(&mut self, i: i32, j: i32, k: i32, l: i32) {
+        self.seeds = [i, j, k, l];
+        self.draws = 0;
+        let mut i = i;
+        let mut j = j;
+        let mut k = k;
+        let mut l = l;
+        for ii in 1..=97 {
+            let mut s = 0.0;
+            let mut t = 0.5;
+            for _jj in 1..=24 {
+                let m = ((i * j % 179) * k) % 179;
+                i = j;
+                j = k;
+                k = m;
+                l = (53 * l + 1) % 169;
+                if l * m % 64 >= 32 {
+                    s += t;
+                }
+                t *= 0.5;
+            }
+            self.uni_u[ii] = s;
+        }
+// Slot 0 is never filled above but is read by uni, so clear it for reseeds to match a fresh start.
+        self.uni_u[0] = 0.0;
+// Set fixed correction values
+        self.uni_c = 362436.0 / 16777216.0;
+        self.uni_cd = 7654321.0 / 16777216.0;
+        self.uni_cm = 16777213.0 / 16777216.0;
+        self.uni_ui = 97;
+        self.uni_uj = 33;
+        self.initialized = true;
+    }
+
+// Validates and decomposes a single seed into four seeds, then initialises the random values array.
+    pub fn rinit(&mut self, ijkl: i32) {
+        let (i, j, k, l) = rinit_seeds(ijkl);
+        self.rstart(i, j, k, l);
+    }
+
+// Generates a value in [a, b) for finite a < b, panicking otherwise.
+// The value is a*(1-u) + b*u worked in f64, where both products are exact, so
+// there is no cancellation even when b-a would overflow f32; a fused multiply-add
+// would change nothing. After rounding to f32 the result can never be below a,
+// and a result that rounds up to b is replaced by the largest f32 below b.
+    pub fn uni_scaled(&mut self, a: f32, b: f32) -> f32 {
+        if !(a.is_finite() && b.is_finite() && a < b) {
+            panic!("uni_scaled: [{}, {}) is not a finite non-empty range", a, b);
+        }
+        let u = self.uni() as f64;
+        let x = ((a as f64) * (1.0 - u) + (b as f64) * u) as f32;
+        if x >= b {
+            b.next_down()
+        } else {
+            x.max(a)
+        }
+    }
+
+// Returns the next output exactly as the fraction numerator / 16777216 (2^24), with no floating point.
+    pub fn fraction(&mut self) -> (u32, u32) {
+        (self.next_word(), 1 << 24)
+    }
+
+// Shuffles items in place with Fisher-Yates; every order is equally likely.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    pub(crate) fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.gen_range_u64(0..i as u64 + 1) as usize);
+        }
+    }
+
+// Returns the next output as its exact 24-bit integer numerator.
+    pub(crate) fn next_word(&mut self) -> u32 {
+        (self.uni() * TWO_24) as u32
+    }
+
+// Returns a uniform 32-bit integer: all 24 bits of one output then the top 8 of the next.
+    pub fn next_u32(&mut self) -> u32 {
+        (self.next_word() << 8) | (self.next_word() >> 16)
+    }
+
+// Returns a uniform 64-bit integer built from three outputs, taking 24, 24 and then 16 bits.
+    pub fn next_u64(&mut self) -> u64 {
+        let high = self.next_word() as u64;
+        let middle = self.next_word() as u64;
+        let low = self.next_word() as u64;
+        (high << 40) | (middle << 16) | (low >> 8)
+    }
+
+// Fills buf with the next buf.len() outputs of uni, bit for bit. The indices and
+// correction are kept in locals for the whole loop and written back once, so the
+// per-call seeding check and field traffic of uni are paid only once per slice.
+// Panics if the generator has not been seeded, as uni does.
+    pub fn fill_f32(&mut self, buf: &mut [f32]) {
+        if !self.initialized {
+            not_initialized();
+        }
+        let lowest = self.indexing as usize;
+        let (mut ui, mut uj, mut c) = (self.uni_ui, self.uni_uj, self.uni_c);
+        let (cd, cm) = (self.uni_cd, self.uni_cm);
+        let u = &mut self.uni_u;
+        for x in buf.iter_mut() {
+            let (i, j) = (ui.min(LEN_U - 1), uj.min(LEN_U - 1));
+            let mut luni = u[i] - u[j];
+            if luni < 0.0 {
+                luni += 1.0;
+            }
+            u[i] = luni;
+            ui = if i <= lowest { 97 } else { i - 1 };
+            uj = if j <= lowest { 97 } else { j - 1 };
+            c -= cd;
+            if c < 0.0 {
+                c += cm;
+            }
+            luni -= c;
+            if luni < 0.0 {
+                luni += 1.0;
+            }
+            *x = luni;
+        }
+        (self.uni_ui, self.uni_uj, self.uni_c) = (ui, uj, c);
+        self.draws = self.draws.wrapping_add(buf.len() as u64);
+    }
+
+// Fills buf with values in [0, 1), each built from two outputs as their 48-bit
+// numerator over 2^48; the stream equals repeated gen_range_f64(0.0..1.0).
+    pub fn fill_f64(&mut self, buf: &mut [f64]) {
+        let mut words = [0.0f32; 512];
+        for chunk in buf.chunks_mut(words.len() / 2) {
+            let words = &mut words[..2 * chunk.len()];
+            self.fill_f32(words);
+            for (x, pair) in chunk.iter_mut().zip(words.chunks_exact(2)) {
+                let high = (pair[0] * TWO_24) as u64;
+                let low = (pair[1] * TWO_24) as u64;
+                *x = ((high << 24) | low) as f64 / (1u64 << 48) as f64;
+            }
+        }
+    }
+
+// Returns an unbiased integer in 0..n by rejecting the uneven top of the 24-bit range.
+    pub fn uni_below(&mut self, n: u32) -> u32 {
+        assert!(n > 0 && n <= 1 << 24, "uni_below: n = {} -- out of range", n);
+        let zone = (1 << 24) - (1 << 24) % n;
+        loop {
+            let w = self.next_word();
+            if w < zone {
+                return w % n;
+            }
+        }
+    }
+
+// Returns a uniform integer in low..high, panicking if the range is empty.
+// Uses Lemire's multiply-and-reject method on next_u32, so there is no modulo
+// bias and a division is needed only in the rare case that a rejection is possible.
+    pub fn gen_range_u32(&mut self, range: Range<u32>) -> u32 {
+        let Range { start, end } = range;
+        assert!(start < end, "gen_range_u32: {}..{} is empty", start, end);
+        let span = end - start;
+        let mut m = self.next_u32() as u64 * span as u64;
+        if (m as u32) < span {
+            let threshold = span.wrapping_neg() % span;
+            while (m as u32) < threshold {
+                m = self.next_u32() as u64 * span as u64;
+            }
+        }
+        start + (m >> 32) as u32
+    }
+
+// The 64-bit counterpart of gen_range_u32, built on next_u64.
+    pub fn gen_range_u64(&mut self, range: Range<u64>) -> u64 {
+        let Range { start, end } = range;
+        assert!(start < end, "gen_range_u64: {}..{} is empty", start, end);
+        let span = end - start;
+        let mut m = self.next_u64() as u128 * span as u128;
+        if (m as u64) < span {
+            let threshold = span.wrapping_neg() % span;
+            while (m as u64) < threshold {
+                m = self.next_u64() as u128 * span as u128;
+            }
+        }
+        start + (m >> 64) as u64
+    }
+
+// As gen_range_u64 for low..=high, which may cover every u64.
+    pub fn gen_range_inclusive_u64(&mut self, range: RangeInclusive<u64>) -> u64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "gen_range_inclusive_u64: {}..={} is empty", start, end);
+        match end.checked_add(1) {
+            Some(stop) => self.gen_range_u64(start..stop),
+            None if start == 0 => self.next_u64(),
+            None => self.gen_range_u64(start - 1..end) + 1,
+        }
+    }
+}
+
+#[cfg(feature = "legacy")]
+impl Default for MarsagliaUniRng {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MarsagliaUniRng {
+// Visits the state as 32-bit words: the table, the corrections, then the indices.
+    fn for_each_state_word(&self, mut f: impl FnMut(u32)) {
+        for value in &self.uni_u {
+            f(value.to_bits());
+        }
+        f(self.uni_c.to_bits());
+        f(self.uni_cd.to_bits());
+        f(self.uni_cm.to_bits());
+        f(self.uni_ui as u32);
+        f(self.uni_uj as u32);
+        // Only non-default indexing adds a word, so Port digests are unchanged.
+        if self.indexing != Indexing::Port {
+            f(self.indexing as u32);
+        }
+    }
+
+// Returns a stable 32-byte digest of the state, for keying caches (not a cryptographic hash).
+    pub fn state_digest(&self) -> [u8; 32] {
+        // Four FNV-1a lanes with different offsets, each finished with the SplitMix64 mixer.
+        let mut lanes = [
+            0xcbf2_9ce4_8422_2325,
+            0x9e37_79b9_7f4a_7c15,
+            0xbf58_476d_1ce4_e5b9,
+            0x94d0_49bb_1331_11eb,
+        ]
+        .map(Fnv1a::with_offset);
+        self.for_each_state_word(|word| {
+            for lane in lanes.iter_mut() {
+                lane.write(&word.to_le_bytes());
+            }
+        });
+        let mut digest = [0u8; 32];
+        for (chunk, lane) in digest.chunks_exact_mut(8).zip(lanes) {
+            chunk.copy_from_slice(&mix64(lane.finish()).to_le_bytes());
+        }
+        digest
+    }
+}
+
+// A generator that has not been seeded yet; seeding it is the only way to get values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Unseeded {
+    indexing: Indexing,
+}
+
+impl Unseeded {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+// Seeding with rinit or rstart keeps the indexing.
+    pub fn with_indexing(indexing: Indexing) -> Self {
+        Self { indexing }
+    }
+
+    pub fn indexing(&self) -> Indexing {
+        self.indexing
+    }
+
+// Seeds from a single value in 0..=900_000_000, as MarsagliaUniRng::rinit does.
+    pub fn rinit(self, ijkl: i32) -> MarsagliaUniRng {
+        let mut rng = MarsagliaUniRng::blank(self.indexing);
+        rng.rinit(ijkl);
+        rng
+    }
+
+// As rinit, returning an error instead of panicking on a bad seed.
+    pub fn try_rinit(self, ijkl: i32) -> Result<MarsagliaUniRng, SeedError> {
+        let mut rng = MarsagliaUniRng::blank(self.indexing);
+        rng.try_rinit(ijkl)?;
+        Ok(rng)
+    }
+
+// Seeds from four values, as MarsagliaUniRng::rstart does.
+    pub fn rstart(self, i: i32, j: i32, k: i32, l: i32) -> MarsagliaUniRng {
+        let mut rng = MarsagliaUniRng::blank(self.indexing);
+        rng.rstart(i, j, k, l);
+        rng
+    }
+
+// As rstart, returning an error instead of panicking on a bad seed.
+    pub fn try_rstart(self, i: i32, j: i32, k: i32, l: i32) -> Result<MarsagliaUniRng, SeedError> {
+        let mut rng = MarsagliaUniRng::blank(self.indexing);
+        rng.try_rstart(i, j, k, l)?;
+        Ok(rng)
+    }
+}
+
+impl MarsagliaUniRng {
+// The same as new() without the legacy feature, so code written against
+// Unseeded builds either way.
+    pub fn unseeded() -> Unseeded {
+        Unseeded::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl MarsagliaUniRng {
+// Seeds from the system clock and returns the seed chosen, so a run can be repeated with rinit.
+    pub fn from_time() -> (Self, i32) {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        let mixed = mix64(now.as_secs() ^ ((now.subsec_nanos() as u64) << 32));
+        let seed = (mixed % 900_000_001) as i32;
+        (Self::unseeded().rinit(seed), seed)
+    }
+}
+
+// Generators compare equal when their states are bit-for-bit identical, so equal
+// generators always produce the same future stream.
+impl PartialEq for MarsagliaUniRng {
+    fn eq(&self, other: &Self) -> bool {
+        let same = |a: f32, b: f32| a.to_bits() == b.to_bits();
+        self.uni_u.iter().zip(&other.uni_u).all(|(a, b)| same(*a, *b))
+            && same(self.uni_c, other.uni_c)
+            && same(self.uni_cd, other.uni_cd)
+            && same(self.uni_cm, other.uni_cm)
+            && self.uni_ui == other.uni_ui
+            && self.uni_uj == other.uni_uj
+            && self.indexing == other.indexing
+    }
+}
+
+impl Eq for MarsagliaUniRng {}
+
+impl Hash for MarsagliaUniRng {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.for_each_state_word(|word| state.write_u32(word));
+    }
+}
@@ -0,0 +1,123 @@
+// The double-precision variant: the same lagged-Fibonacci scheme carried in f64,
+// giving outputs with 53 bits of mantissa.
+//
+// This follows the double-precision RANMAR used in scientific codes: each of the
+// 97 table entries takes 53 bits from the seed sequence rather than 24, the
+// correction constants are scaled by 2^53 instead of 2^24, and the indices cycle
+// through slots 1..=97 (lags 97 and 33). Every value is a multiple of 2^-53 in
+// [0, 1), so the subtractions are exact and the stream is the same on every
+// platform. It shares seeds with MarsagliaUniRng but not outputs.
+
+use crate::generator::rinit_seeds;
+
+const TWO_53: f64 = 9007199254740992.0; // Scale of the 53-bit output fraction.
+
+#[cold]
+fn not_initialized() -> ! {
+    panic!("uni: generator used before rinit or rstart");
+}
+
+// Marsaglia's Universal Random Number Generator in double precision.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarsagliaUniRng64 {
+    uni_u: [f64; 98], // Slots 1..=97 hold the recent random numbers; slot 0 is unused.
+    uni_c: f64,       // Correction to avoid periodicity.
+    uni_cd: f64,      // Correction delta value.
+    uni_cm: f64,      // Correction modulus.
+    uni_ui: usize,    // Current position in the random values array.
+    uni_uj: usize,
+    initialized: bool, // Set once rinit or rstart has filled the table.
+}
+
+impl MarsagliaUniRng64 {
+    // Constructor for the random number generator.
+    pub fn new() -> Self {
+        Self {
+            uni_u: [0.0; 98],
+            uni_c: 0.0,
+            uni_cd: 0.0,
+            uni_cm: 0.0,
+            uni_ui: 0,
+            uni_uj: 0,
+            initialized: false,
+        }
+    }
+
+    // Generate a new random value in [0, 1) with 53 random bits.
+    // Panics if the generator has not been seeded with rinit or rstart.
+    pub fn uni(&mut self) -> f64 {
+        if !self.initialized {
+            not_initialized();
+        }
+        // As in MarsagliaUniRng::uni, the clamps never bind but remove the bounds checks.
+        let ui = self.uni_ui.min(97);
+        let uj = self.uni_uj.min(97);
+        let mut luni = self.uni_u[ui] - self.uni_u[uj];
+        if luni < 0.0 {
+            luni += 1.0;
+        }
+        self.uni_u[ui] = luni;
+
+        self.uni_ui = if ui <= 1 { 97 } else { ui - 1 };
+        self.uni_uj = if uj <= 1 { 97 } else { uj - 1 };
+
+        self.uni_c -= self.uni_cd;
+        if self.uni_c < 0.0 {
+            self.uni_c += self.uni_cm;
+        }
+
+        luni -= self.uni_c;
+        if luni < 0.0 {
+            luni += 1.0;
+        }
+        luni
+    }
+
+    // Returns the next output as its exact 53-bit integer numerator.
+    pub fn next_word(&mut self) -> u64 {
+        (self.uni() * TWO_53) as u64
+    }
+
+    // Initialises the random values array using four seeds.
+    pub fn rstart(&mut self, i: i32, j: i32, k: i32, l: i32) {
+        let mut i = i;
+        let mut j = j;
+        let mut k = k;
+        let mut l = l;
+        for ii in 1..=97 {
+            let mut s = 0.0;
+            let mut t = 0.5;
+            for _jj in 1..=53 {
+                let m = ((i * j % 179) * k) % 179;
+                i = j;
+                j = k;
+                k = m;
+                l = (53 * l + 1) % 169;
+                if l * m % 64 >= 32 {
+                    s += t;
+                }
+                t *= 0.5;
+            }
+            self.uni_u[ii] = s;
+        }
+        // Set fixed correction values
+        self.uni_c = 362436069876.0 / TWO_53;
+        self.uni_cd = 7654321234567.0 / TWO_53;
+        self.uni_cm = 9007199254740881.0 / TWO_53;
+        self.uni_ui = 97;
+        self.uni_uj = 33;
+        self.initialized = true;
+    }
+
+    // Validates and decomposes a single seed into four seeds, then initialises the random values array.
+    pub fn rinit(&mut self, ijkl: i32) {
+        let (i, j, k, l) = rinit_seeds(ijkl);
+        self.rstart(i, j, k, l);
+    }
+}
+
+impl Default for MarsagliaUniRng64 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
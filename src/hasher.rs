@@ -0,0 +1,131 @@
+// A seeded hasher for HashMap and HashSet, so iteration order is reproducible.
+//
+// The standard library's RandomState picks a new key for every map, so the
+// order a map iterates in changes from run to run. A map built with
+// UnirandBuildHasher hashes every key the same way for a given seed, so the
+// same sequence of inserts and removes always leaves the same order. Hashes
+// are taken over little-endian encodings and agree across platforms. The
+// hasher is not resistant to deliberately colliding keys, so it suits tests
+// and simulations rather than maps of untrusted input.
+
+use core::hash::{BuildHasher, Hasher};
+
+use crate::generator::mix64;
+use crate::MarsagliaUniRng;
+
+// Builds UnirandHashers that all start from the same seed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct UnirandBuildHasher {
+    seed: u64,
+}
+
+impl UnirandBuildHasher {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    // Takes the seed from the next 64 bits of rng, so a map's order follows
+    // from the simulation's master seed.
+    pub fn from_rng(rng: &mut MarsagliaUniRng) -> Self {
+        Self::new(rng.next_u64())
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl BuildHasher for UnirandBuildHasher {
+    type Hasher = UnirandHasher;
+
+    fn build_hasher(&self) -> UnirandHasher {
+        UnirandHasher(Fnv1a::with_offset(FNV_OFFSET ^ mix64(self.seed)))
+    }
+}
+
+// Seeded FNV-1a, finished with the SplitMix64 mixer so every bit of the
+// hash depends on every input byte.
+#[derive(Clone, Debug)]
+pub struct UnirandHasher(Fnv1a);
+
+impl Hasher for UnirandHasher {
+    fn finish(&self) -> u64 {
+        mix64(self.0.finish())
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.write(bytes);
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.0.write_u16(n);
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.0.write_u32(n);
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0.write_u64(n);
+    }
+
+    fn write_u128(&mut self, n: u128) {
+        self.0.write_u128(n);
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.0.write_usize(n);
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+// Plain FNV-1a over little-endian encodings, so hashes agree across platforms
+// and releases. finish returns the raw state, with no final mixing.
+#[derive(Clone, Debug)]
+pub(crate) struct Fnv1a(u64);
+
+impl Fnv1a {
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    pub(crate) fn new() -> Self {
+        Self::with_offset(FNV_OFFSET)
+    }
+
+    // Starts from offset in place of the standard offset basis.
+    pub(crate) fn with_offset(offset: u64) -> Self {
+        Self(offset)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ *byte as u64).wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, n: u128) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+}
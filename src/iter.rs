@@ -0,0 +1,79 @@
+// Iterators over the generator's outputs, for use in iterator pipelines.
+//
+// Both yield the uni stream forever, so bound them with take or zip. Iter
+// borrows the generator, which can be used again once the iterator is dropped;
+// IntoIter owns it and hands it back from into_inner.
+
+use core::iter::FusedIterator;
+
+use crate::MarsagliaUniRng;
+
+#[derive(Debug)]
+pub struct Iter<'a> {
+    rng: &'a mut MarsagliaUniRng,
+}
+
+#[derive(Clone, Debug)]
+pub struct IntoIter {
+    rng: MarsagliaUniRng,
+}
+
+impl MarsagliaUniRng {
+    // Returns an endless iterator of uni outputs borrowing this generator.
+    pub fn iter(&mut self) -> Iter<'_> {
+        Iter { rng: self }
+    }
+}
+
+impl IntoIter {
+    // Returns the generator, positioned after the last value yielded.
+    pub fn into_inner(self) -> MarsagliaUniRng {
+        self.rng
+    }
+}
+
+impl Iterator for Iter<'_> {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        Some(self.rng.uni())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl Iterator for IntoIter {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        Some(self.rng.uni())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl FusedIterator for Iter<'_> {}
+
+impl FusedIterator for IntoIter {}
+
+impl IntoIterator for MarsagliaUniRng {
+    type Item = f32;
+    type IntoIter = IntoIter;
+
+    fn into_iter(self) -> IntoIter {
+        IntoIter { rng: self }
+    }
+}
+
+impl<'a> IntoIterator for &'a mut MarsagliaUniRng {
+    type Item = f32;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
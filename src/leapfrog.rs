@@ -0,0 +1,66 @@
+// Leapfrog streams: stream k of K takes values k, k + K, k + 2K, ... of one
+// base sequence.
+//
+// The K streams of a seed never overlap and together hold every value of the
+// base sequence, so a parallel run gives the same set of draws whatever the
+// number of workers that share it. Each draw skips the K - 1 values owned by
+// the other streams with discard, so streams far apart cost O(log K) a draw.
+
+use crate::MarsagliaUniRng;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Leapfrog {
+    rng: MarsagliaUniRng, // Positioned at this stream's next value.
+    stream_index: u64,
+    num_streams: u64,
+}
+
+impl MarsagliaUniRng {
+    // Returns stream stream_index of num_streams over the sequence of
+    // rinit(seed). Panics if seed is out of range for rinit or stream_index is
+    // not below num_streams.
+    pub fn leapfrog(seed: i32, stream_index: u64, num_streams: u64) -> Leapfrog {
+        assert!(
+            stream_index < num_streams,
+            "leapfrog: stream_index = {}, num_streams = {} -- out of range",
+            stream_index,
+            num_streams
+        );
+        let mut rng = Self::unseeded().rinit(seed);
+        rng.discard(stream_index);
+        Leapfrog {
+            rng,
+            stream_index,
+            num_streams,
+        }
+    }
+}
+
+impl Leapfrog {
+    pub fn stream_index(&self) -> u64 {
+        self.stream_index
+    }
+
+    pub fn num_streams(&self) -> u64 {
+        self.num_streams
+    }
+
+    // Returns this stream's next value, as uni would.
+    pub fn uni(&mut self) -> f32 {
+        let x = self.rng.uni();
+        self.rng.discard(self.num_streams - 1);
+        x
+    }
+
+    // Returns this stream's next value as its exact fraction, as fraction would.
+    pub fn fraction(&mut self) -> (u32, u32) {
+        let x = self.rng.fraction();
+        self.rng.discard(self.num_streams - 1);
+        x
+    }
+
+    // Returns the base generator, positioned at this stream's next value.
+    pub fn into_inner(self) -> MarsagliaUniRng {
+        self.rng
+    }
+}
@@ -0,0 +1,184 @@
+// Rust version of Marsaglia's UNI random number generator
+// Ported from C version provided by Edinburough University
+// Ported by R. Neale info@maths.earth 2023 under MIT License
+//
+// The generator and the types most code needs live at the crate root, and
+// `use unirand::prelude::*` imports them with the common distributions.
+// distributions, sampling and stats hold the general-purpose samplers and
+// checks; formats, testdata and science group the specialised modules. The
+// flat module paths of earlier versions remain as deprecated aliases at the
+// end of this file.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![forbid(unsafe_code)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod checkpoint;
+mod discard;
+mod endian;
+#[cfg(feature = "std")]
+mod ensemble;
+mod error;
+mod family;
+mod generator;
+mod generator64;
+mod hasher;
+mod iter;
+mod leapfrog;
+#[cfg(feature = "std")]
+mod parallel;
+mod range;
+mod ranmar;
+#[cfg(feature = "std")]
+mod reader;
+#[cfg(feature = "std")]
+mod registry;
+mod seed;
+mod state;
+#[cfg(feature = "std")]
+mod sync;
+#[cfg(feature = "std")]
+mod thread;
+#[cfg(feature = "trace")]
+mod trace;
+mod validate;
+
+#[cfg(feature = "std")]
+pub mod compat;
+#[cfg(feature = "std")]
+pub mod distributions;
+pub mod formats;
+#[cfg(feature = "std")]
+pub mod sampling;
+#[cfg(feature = "std")]
+pub mod scheduling;
+#[cfg(feature = "std")]
+pub mod science;
+#[cfg(feature = "std")]
+pub mod stats;
+pub mod testdata;
+#[cfg(feature = "std")]
+pub mod tournament;
+
+pub use checkpoint::Checkpoint;
+pub use endian::Endian;
+pub use error::Error;
+pub use family::{StreamFamily, MAX_STREAMS};
+#[cfg(feature = "std")]
+pub use ensemble::{Ensemble, SeededRun};
+pub use generator::{MarsagliaUniRng, Unseeded};
+pub use generator64::MarsagliaUniRng64;
+pub use hasher::{UnirandBuildHasher, UnirandHasher};
+pub use iter::{IntoIter, Iter};
+pub use leapfrog::Leapfrog;
+pub use range::{RangeError, RejectionLimitError};
+pub use ranmar::Indexing;
+#[cfg(feature = "std")]
+pub use reader::RngReader;
+#[cfg(feature = "std")]
+pub use registry::{RegistryCheckpoint, RngRegistry};
+#[cfg(feature = "trace")]
+pub use trace::StepTrace;
+pub use seed::SeedError;
+pub use state::{StateError, STATE_LEN, STATE_VERSION};
+#[cfg(feature = "std")]
+pub use sync::SyncMarsagliaUniRng;
+#[cfg(feature = "std")]
+pub use thread::{range, seed_thread_rng, thread_rng_seed, uni, with_thread_rng};
+pub use validate::{InvariantViolation, SelfTestError};
+
+#[cfg(feature = "std")]
+pub(crate) use generator::mix64;
+pub(crate) use generator::{LEN_U, TWO_24};
+
+// The items most programs use, for glob import.
+pub mod prelude {
+    #[cfg(feature = "std")]
+    pub use crate::distributions::{Exponential, Gumbel, LogNormal, Normal, Zipf};
+    pub use crate::{Checkpoint, Endian, Indexing, MarsagliaUniRng, MarsagliaUniRng64};
+    #[cfg(feature = "std")]
+    pub use crate::{Ensemble, RngReader, RngRegistry};
+}
+
+// Flat paths from before the modules were grouped, each an alias of its new
+// home. They will be removed in a later release.
+#[cfg(feature = "std")]
+#[deprecated(note = "moved to unirand::formats::archive")]
+pub mod archive {
+    pub use crate::formats::archive::*;
+}
+#[cfg(feature = "std")]
+#[deprecated(note = "moved to unirand::formats::export")]
+pub mod export {
+    pub use crate::formats::export::*;
+}
+#[deprecated(note = "moved to unirand::formats::table")]
+pub mod table {
+    pub use crate::formats::table::*;
+}
+#[deprecated(note = "moved to unirand::testdata::arrays")]
+pub mod arrays {
+    pub use crate::testdata::arrays::*;
+}
+#[cfg(feature = "std")]
+#[deprecated(note = "moved to unirand::testdata::bio")]
+pub mod bio {
+    pub use crate::testdata::bio::*;
+}
+#[cfg(feature = "std")]
+#[deprecated(note = "moved to unirand::testdata::boolean")]
+pub mod boolean {
+    pub use crate::testdata::boolean::*;
+}
+#[cfg(feature = "std")]
+#[deprecated(note = "moved to unirand::testdata::datasets")]
+pub mod datasets {
+    pub use crate::testdata::datasets::*;
+}
+#[cfg(feature = "std")]
+#[deprecated(note = "moved to unirand::testdata::filetree")]
+pub mod filetree {
+    pub use crate::testdata::filetree::*;
+}
+#[cfg(feature = "alloc")]
+#[deprecated(note = "moved to unirand::testdata::sat")]
+pub mod sat {
+    pub use crate::testdata::sat::*;
+}
+#[cfg(feature = "alloc")]
+#[deprecated(note = "moved to unirand::testdata::unicode")]
+pub mod unicode {
+    pub use crate::testdata::unicode::*;
+}
+#[cfg(feature = "std")]
+#[deprecated(note = "moved to unirand::testdata::workload")]
+pub mod workload {
+    pub use crate::testdata::workload::*;
+}
+#[cfg(all(feature = "std", feature = "unstable"))]
+#[deprecated(note = "moved to unirand::science::dp")]
+pub mod dp {
+    pub use crate::science::dp::*;
+}
+#[cfg(all(feature = "std", feature = "unstable"))]
+#[deprecated(note = "moved to unirand::science::optimise")]
+pub mod optimise {
+    pub use crate::science::optimise::*;
+}
+#[cfg(feature = "std")]
+#[deprecated(note = "moved to unirand::science::physics")]
+pub mod physics {
+    pub use crate::science::physics::*;
+}
+#[cfg(feature = "std")]
+#[deprecated(note = "moved to unirand::science::rotation")]
+pub mod rotation {
+    pub use crate::science::rotation::*;
+}
+#[cfg(feature = "std")]
+#[deprecated(note = "moved to unirand::science::sensitivity")]
+pub mod sensitivity {
+    pub use crate::science::sensitivity::*;
+}
@@ -0,0 +1,84 @@
+// Filling large buffers on several threads, with the same result as one.
+//
+// The buffer is split into one contiguous part per thread. Each thread clones
+// the generator, jumps it to the start of its part with discard and fills the
+// part with fill_f32, so the parts are non-overlapping stretches of a single
+// sequence and the buffer holds exactly what fill_f32 would have written,
+// whatever the number of threads.
+//
+// StreamFamily::par_fill_f32 is the master-seed form: the buffer is cut into
+// blocks of BLOCK values and block b is filled from stream b of the family,
+// so the blocks are independent substreams and the result depends only on
+// the master seed, not on the thread count.
+
+use std::thread;
+
+use crate::{MarsagliaUniRng, StreamFamily};
+
+// Parts shorter than this are not worth a thread and a jump.
+const MIN_PART: usize = 1 << 16;
+
+// Values per stream in StreamFamily::par_fill_f32.
+const BLOCK: usize = 1 << 16;
+
+fn available_threads() -> usize {
+    thread::available_parallelism().map_or(1, |n| n.get())
+}
+
+impl MarsagliaUniRng {
+    // As fill_f32, using every available core. Panics if the generator has
+    // not been seeded.
+    pub fn par_fill_f32(&mut self, buf: &mut [f32]) {
+        self.par_fill_f32_threads(buf, available_threads());
+    }
+
+    // As fill_f32, on at most the given number of threads. Panics if the
+    // generator has not been seeded.
+    pub fn par_fill_f32_threads(&mut self, buf: &mut [f32], threads: usize) {
+        let threads = threads.clamp(1, buf.len().div_ceil(MIN_PART).max(1));
+        if threads == 1 || !self.initialized {
+            self.fill_f32(buf);
+            return;
+        }
+        let part = buf.len().div_ceil(threads);
+        let start = &*self;
+        thread::scope(|scope| {
+            for (n, chunk) in buf.chunks_mut(part).enumerate() {
+                scope.spawn(move || {
+                    let mut rng = start.clone();
+                    rng.discard((n * part) as u64);
+                    rng.fill_f32(chunk);
+                });
+            }
+        });
+        self.discard(buf.len() as u64);
+    }
+}
+
+impl StreamFamily {
+    // Fills buf with independent substreams of the family, using every
+    // available core: StreamFamily::new(master_seed).par_fill_f32(buf). Panics
+    // if buf needs more than MAX_STREAMS blocks.
+    pub fn par_fill_f32(&self, buf: &mut [f32]) {
+        self.par_fill_f32_threads(buf, available_threads());
+    }
+
+    // As par_fill_f32, on at most the given number of threads. The result is
+    // the same for every thread count.
+    pub fn par_fill_f32_threads(&self, buf: &mut [f32], threads: usize) {
+        let blocks = buf.len().div_ceil(BLOCK);
+        let threads = threads.clamp(1, blocks.max(1));
+        // Each thread takes a run of whole blocks.
+        let part = blocks.div_ceil(threads).max(1) * BLOCK;
+        thread::scope(|scope| {
+            for (n, chunk) in buf.chunks_mut(part).enumerate() {
+                scope.spawn(move || {
+                    let first = (n * part / BLOCK) as u64;
+                    for (b, block) in chunk.chunks_mut(BLOCK).enumerate() {
+                        self.stream(first + b as u64).fill_f32(block);
+                    }
+                });
+            }
+        });
+    }
+}
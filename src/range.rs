@@ -0,0 +1,158 @@
+// Uniform values in a caller's range, and rejection limits for bounded draws.
+//
+// The floating-point methods return values in the half-open interval [low, high): low can be
+// returned, high never is. The value is low*(1-u) + high*u rather than
+// low + (high-low)*u, so ranges whose width overflows, such as -MAX..MAX, work.
+// Ranges that are empty, inverted or not finite are reported as errors.
+//
+// The _limited integer methods take a cap on the candidate draws their
+// rejection step may make, returning RejectionLimitError when it is reached
+// rather than looping on. Otherwise they return what gen_range_u32 and
+// gen_range_u64 would.
+
+use core::ops::Range;
+
+use crate::MarsagliaUniRng;
+
+// Why a range passed to gen_range_f32 or gen_range_f64 was rejected.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RangeError {
+    NotFinite, // An endpoint is NaN or infinite.
+    Empty,     // low equals high, so there is no value to return.
+    Inverted,  // low is greater than high.
+}
+
+impl core::fmt::Display for RangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(match self {
+            RangeError::NotFinite => "range endpoint is not finite",
+            RangeError::Empty => "range is empty",
+            RangeError::Inverted => "range start is greater than its end",
+        })
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RangeError {}
+
+// A rejection sampler gave up after drawing its limit of candidates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RejectionLimitError {
+    pub limit: u64, // The number of candidates drawn, all rejected.
+}
+
+impl core::fmt::Display for RejectionLimitError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "no candidate accepted in {} draws", self.limit)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RejectionLimitError {}
+
+// Classifies low..high, with NaN endpoints reported as not finite.
+fn check<T: PartialOrd>(low: T, high: T, finite: bool) -> Result<(), RangeError> {
+    if !finite {
+        Err(RangeError::NotFinite)
+    } else if low == high {
+        Err(RangeError::Empty)
+    } else if low > high {
+        Err(RangeError::Inverted)
+    } else {
+        Ok(())
+    }
+}
+
+impl MarsagliaUniRng {
+    // Returns a value in [low, high) from one output, as uni_scaled does.
+    pub fn gen_range_f32(&mut self, range: Range<f32>) -> Result<f32, RangeError> {
+        let Range { start, end } = range;
+        check(start, end, start.is_finite() && end.is_finite())?;
+        Ok(self.uni_scaled(start, end))
+    }
+
+    // Returns a value in [low, high) from two outputs, so u has 48 random bits.
+    pub fn gen_range_f64(&mut self, range: Range<f64>) -> Result<f64, RangeError> {
+        let Range { start, end } = range;
+        check(start, end, start.is_finite() && end.is_finite())?;
+        let bits = ((self.next_word() as u64) << 24) | self.next_word() as u64;
+        let u = bits as f64 / (1u64 << 48) as f64;
+        let x = start * (1.0 - u) + end * u;
+        // Rounding can carry x just outside the range; pull it back in.
+        Ok(if x >= end {
+            end.next_down()
+        } else {
+            x.max(start)
+        })
+    }
+}
+
+impl MarsagliaUniRng {
+    // As gen_range_u32, drawing at most max_draws candidates. Panics if the
+    // range is empty or max_draws is 0.
+    pub fn gen_range_u32_limited(
+        &mut self,
+        range: Range<u32>,
+        max_draws: u64,
+    ) -> Result<u32, RejectionLimitError> {
+        let Range { start, end } = range;
+        assert!(
+            start < end,
+            "gen_range_u32_limited: {}..{} is empty",
+            start,
+            end
+        );
+        assert!(
+            max_draws > 0,
+            "gen_range_u32_limited: max_draws must be positive"
+        );
+        let span = end - start;
+        let mut m = self.next_u32() as u64 * span as u64;
+        if (m as u32) < span {
+            let threshold = span.wrapping_neg() % span;
+            let mut drawn = 1;
+            while (m as u32) < threshold {
+                if drawn == max_draws {
+                    return Err(RejectionLimitError { limit: max_draws });
+                }
+                m = self.next_u32() as u64 * span as u64;
+                drawn += 1;
+            }
+        }
+        Ok(start + (m >> 32) as u32)
+    }
+
+    // As gen_range_u64, drawing at most max_draws candidates.
+    pub fn gen_range_u64_limited(
+        &mut self,
+        range: Range<u64>,
+        max_draws: u64,
+    ) -> Result<u64, RejectionLimitError> {
+        let Range { start, end } = range;
+        assert!(
+            start < end,
+            "gen_range_u64_limited: {}..{} is empty",
+            start,
+            end
+        );
+        assert!(
+            max_draws > 0,
+            "gen_range_u64_limited: max_draws must be positive"
+        );
+        let span = end - start;
+        let mut m = self.next_u64() as u128 * span as u128;
+        if (m as u64) < span {
+            let threshold = span.wrapping_neg() % span;
+            let mut drawn = 1;
+            while (m as u64) < threshold {
+                if drawn == max_draws {
+                    return Err(RejectionLimitError { limit: max_draws });
+                }
+                m = self.next_u64() as u128 * span as u128;
+                drawn += 1;
+            }
+        }
+        Ok(start + (m >> 64) as u64)
+    }
+}
@@ -0,0 +1,99 @@
+// Compatibility with the published RANMAR stream.
+//
+// The original port steps ui and uj down through all 98 table slots,
+// including slot 0, which rstart never fills, so its lags are 98 and 34. RANMAR
+// as published by Marsaglia, Zaman and James, and as shipped in GSL
+// (gsl_rng_ranmar) and CERNLIB (V113), cycles over slots 1..=97 only, giving
+// lags 97 and 33. Both share rstart and the correction sequence, so the two
+// streams agree until ui or uj first wraps and then diverge.
+//
+// Indexing::Ranmar reproduces the published stream bit for bit: uni equals
+// GSL's gsl_rng_uniform and next_word (or the numerator of fraction) equals
+// gsl_rng_get. GSL maps a seed s to the rstart seeds as rinit does, after
+// replacing s = 0 by 1 and taking ij = (s / 30082) % 31329 and kl = s % 30082;
+// for 1 <= s <= 900_000_000 the modulus changes nothing, so
+// gsl_rng_set(r, s) matches with_indexing(Indexing::Ranmar) followed by rinit(s).
+
+use crate::{MarsagliaUniRng, Unseeded};
+
+// Which table slots the lag indices cycle through. The discriminant is the lowest slot used.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Indexing {
+    // All 98 slots: this crate's original stream, kept as the default.
+    #[default]
+    Port = 0,
+    // Slots 1..=97: the published RANMAR stream, as in GSL and CERNLIB.
+    Ranmar = 1,
+}
+
+impl MarsagliaUniRng {
+    // An unseeded generator that will use the given indexing once seeded.
+    // Seeding with rinit or rstart keeps the indexing.
+    #[cfg(not(feature = "legacy"))]
+    pub fn with_indexing(indexing: Indexing) -> Unseeded {
+        Unseeded::with_indexing(indexing)
+    }
+
+    #[cfg(feature = "legacy")]
+    pub fn with_indexing(indexing: Indexing) -> Self {
+        Self::blank(indexing)
+    }
+
+    pub fn indexing(&self) -> Indexing {
+        self.indexing
+    }
+
+    // The state GSL's gsl_rng_set gives gsl_rng_ranmar for seed, accepting
+    // every unsigned long as GSL does.
+    pub fn gsl_ranmar(seed: u64) -> Self {
+        let s = seed.max(1);
+        let ij = (s / 30082 % 31329) as i32;
+        let kl = (s % 30082) as i32;
+        Unseeded::with_indexing(Indexing::Ranmar).rstart(
+            (ij / 177) % 177 + 2,
+            ij % 177 + 2,
+            (kl / 169) % 178 + 1,
+            kl % 169,
+        )
+    }
+}
+
+// CERNLIB counts draws as ntot + ntot2 * 10^9 with ntot below 10^9.
+const CERNLIB_MODCNS: u64 = 1_000_000_000;
+
+impl MarsagliaUniRng {
+    // Resumes a CERNLIB RANMAR stream (RMMAQ with the 'S' option) from its
+    // saved (ijkl, ntot, ntot2): seeds with rinit(ijkl) under Indexing::Ranmar
+    // and skips ntot + ntot2 * 10^9 draws, as CERNLIB's own restart does.
+    // Panics if ijkl is outside 0..=900_000_000.
+    pub fn restart_cernlib(ijkl: i32, ntot: u32, ntot2: u32) -> Self {
+        let mut rng = Unseeded::with_indexing(Indexing::Ranmar).rinit(ijkl);
+        rng.discard(ntot as u64 + ntot2 as u64 * CERNLIB_MODCNS);
+        rng
+    }
+
+    // The (ijkl, ntot, ntot2) triple CERNLIB's RMMAQ 'R' option would save
+    // for this state, for passing back to restart_cernlib or to Fortran code.
+    // None unless the generator uses Indexing::Ranmar and was seeded by
+    // rinit, or by rstart with seeds rinit could have produced.
+    pub fn cernlib_seeds(&self) -> Option<(i32, u32, u32)> {
+        if !self.initialized || self.indexing != Indexing::Ranmar {
+            return None;
+        }
+        let [i, j, k, l] = self.seeds;
+        let in_range = (2..=178).contains(&i)
+            && (2..=178).contains(&j)
+            && (1..=178).contains(&k)
+            && (0..=168).contains(&l);
+        if !in_range {
+            return None;
+        }
+        // Within these ranges rinit's decomposition inverts exactly.
+        let ijkl = ((i - 2) * 177 + (j - 2)) * 30082 + (k - 1) * 169 + l;
+        if ijkl > 900_000_000 {
+            return None;
+        }
+        let ntot2 = u32::try_from(self.draws / CERNLIB_MODCNS).ok()?;
+        Some((ijkl, (self.draws % CERNLIB_MODCNS) as u32, ntot2))
+    }
+}
@@ -0,0 +1,49 @@
+// Infinite byte stream over the generator for use wherever a reader is expected.
+//
+// Each draw contributes the three bytes of its 24-bit numerator, least
+// significant first unless big-endian order is chosen. Bytes left over from a
+// draw are kept for the next read, so the stream is identical however the
+// caller sizes its buffers.
+
+use std::io::{self, Read};
+
+use crate::{Endian, MarsagliaUniRng};
+
+#[derive(Clone, Debug)]
+pub struct RngReader {
+    rng: MarsagliaUniRng,
+    endian: Endian,
+    pending: [u8; 3], // Bytes of the current draw.
+    used: usize,      // How many of the pending bytes have been handed out.
+}
+
+impl RngReader {
+    // Wraps an initialised generator, emitting little-endian words.
+    pub fn new(rng: MarsagliaUniRng) -> Self {
+        Self::with_endian(rng, Endian::Little)
+    }
+
+    pub fn with_endian(rng: MarsagliaUniRng, endian: Endian) -> Self {
+        Self { rng, endian, pending: [0; 3], used: 3 }
+    }
+
+    // Returns the generator, discarding any bytes not yet read.
+    pub fn into_inner(self) -> MarsagliaUniRng {
+        self.rng
+    }
+}
+
+impl Read for RngReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        for byte in buf.iter_mut() {
+            if self.used == 3 {
+                let [b0, b1, b2, _] = self.rng.next_word().to_le_bytes();
+                self.pending = self.endian.order([b0, b1, b2]);
+                self.used = 0;
+            }
+            *byte = self.pending[self.used];
+            self.used += 1;
+        }
+        Ok(buf.len())
+    }
+}
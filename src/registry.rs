@@ -0,0 +1,94 @@
+// Per-key generators derived from one master seed, for entities, regions or agents.
+//
+// Each key's generator is created on first use and seeded from the master
+// seed and a stable hash of the key, so the same key always gets the same
+// stream, whatever order keys are first used in.
+
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+use crate::hasher::Fnv1a;
+use crate::{mix64, MarsagliaUniRng};
+
+#[derive(Clone, Debug)]
+pub struct RngRegistry<K> {
+    master_seed: i32,
+    rngs: HashMap<K, MarsagliaUniRng>,
+}
+
+// Every generator in a registry together with its master seed, as returned by save.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegistryCheckpoint<K> {
+    pub master_seed: i32,
+    pub entries: Vec<(K, MarsagliaUniRng)>,
+}
+
+impl<K: Hash + Eq + Clone> RngRegistry<K> {
+    // Panics if master_seed is outside 0..=900_000_000, as rinit does.
+    pub fn new(master_seed: i32) -> Self {
+        if !(0..=900_000_000).contains(&master_seed) {
+            panic!(
+                "RngRegistry::new: master_seed = {} -- out of range",
+                master_seed
+            );
+        }
+        Self {
+            master_seed,
+            rngs: HashMap::new(),
+        }
+    }
+
+    pub fn master_seed(&self) -> i32 {
+        self.master_seed
+    }
+
+    // Returns the generator for key, creating it on first use.
+    pub fn get(&mut self, key: &K) -> &mut MarsagliaUniRng {
+        if !self.rngs.contains_key(key) {
+            let rng = MarsagliaUniRng::unseeded().rinit(self.derived_seed(key));
+            self.rngs.insert(key.clone(), rng);
+        }
+        self.rngs.get_mut(key).expect("generator was just inserted")
+    }
+
+    // Returns the rinit seed used for key's generator. Distinct keys can share a
+    // seed, with odds of about one in 900 million per pair.
+    pub fn derived_seed(&self, key: &K) -> i32 {
+        let mut hasher = Fnv1a::new();
+        key.hash(&mut hasher);
+        let mixed = mix64(hasher.finish() ^ mix64(self.master_seed as u64));
+        (mixed % 900_000_001) as i32
+    }
+
+    pub fn len(&self) -> usize {
+        self.rngs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rngs.is_empty()
+    }
+
+    // Drops key's generator; the next get starts its stream again from the beginning.
+    pub fn remove(&mut self, key: &K) -> Option<MarsagliaUniRng> {
+        self.rngs.remove(key)
+    }
+
+    // Captures every generator created so far.
+    pub fn save(&self) -> RegistryCheckpoint<K> {
+        RegistryCheckpoint {
+            master_seed: self.master_seed,
+            entries: self
+                .rngs
+                .iter()
+                .map(|(k, rng)| (k.clone(), rng.clone()))
+                .collect(),
+        }
+    }
+
+    // Rebuilds a registry exactly as it was when checkpoint was saved.
+    pub fn restore(checkpoint: RegistryCheckpoint<K>) -> Self {
+        let mut registry = Self::new(checkpoint.master_seed);
+        registry.rngs.extend(checkpoint.entries);
+        registry
+    }
+}
@@ -0,0 +1,111 @@
+// Interactive exploration of the generator for the `repl` subcommand.
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, IsTerminal, Write};
+
+use unirand::MarsagliaUniRng;
+
+use crate::{int_in, parse_seed};
+
+const HELP: &str = "commands:
+  seed N              reinitialise with seed N (0..=900000000)
+  next [N]            print the next N numbers (default 1)
+  range A B           print an integer in A..=B
+  state save NAME     remember the current state as NAME
+  state load NAME     return to the state saved as NAME
+  state list          list saved states
+  help                show this help
+  quit                leave the repl";
+
+// Runs `unirand repl` on standard input and output.
+pub fn run(args: &[String]) -> Result<(), String> {
+    if let Some(extra) = args.first() {
+        return Err(format!("unexpected repl argument '{}'", extra));
+    }
+    let stdin = io::stdin();
+    let interactive = stdin.is_terminal();
+    let stdout = io::stdout();
+    session(stdin.lock(), stdout.lock(), interactive).map_err(|e| e.to_string())
+}
+
+fn session<R: BufRead, W: Write>(input: R, mut out: W, interactive: bool) -> io::Result<()> {
+    let mut rng = MarsagliaUniRng::unseeded().rinit(170);
+    let mut saved: HashMap<String, MarsagliaUniRng> = HashMap::new();
+    if interactive {
+        writeln!(out, "unirand repl, seeded with 170. Type 'help' for commands.")?;
+    }
+    let mut lines = input.lines();
+    loop {
+        if interactive {
+            write!(out, "> ")?;
+            out.flush()?;
+        }
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => break,
+        };
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match execute(&words, &mut rng, &mut saved) {
+            Ok(Some(reply)) => writeln!(out, "{}", reply)?,
+            Ok(None) => {}
+            Err(Quit) => break,
+        }
+    }
+    Ok(())
+}
+
+struct Quit;
+
+// Runs one command, returning the text to print.
+fn execute(
+    words: &[&str],
+    rng: &mut MarsagliaUniRng,
+    saved: &mut HashMap<String, MarsagliaUniRng>,
+) -> Result<Option<String>, Quit> {
+    let reply = match words {
+        [] => return Ok(None),
+        ["quit"] | ["exit"] => return Err(Quit),
+        ["help"] => HELP.to_string(),
+        ["seed", seed] => match parse_seed(seed) {
+            Ok(seed) => {
+                rng.rinit(seed);
+                format!("seeded with {}", seed)
+            }
+            Err(msg) => msg,
+        },
+        ["next"] => rng.uni().to_string(),
+        ["next", count] => match count.parse::<usize>() {
+            Ok(count) => (0..count)
+                .map(|_| rng.uni().to_string())
+                .collect::<Vec<_>>()
+                .join("\n"),
+            Err(_) => format!("'{}' is not a count", count),
+        },
+        ["range", low, high] => match (low.parse::<i64>(), high.parse::<i64>()) {
+            (Ok(low), Ok(high)) if low <= high => int_in(rng, low, high).to_string(),
+            _ => "range needs two integers A <= B".to_string(),
+        },
+        ["state", "save", name] => {
+            saved.insert(name.to_string(), rng.clone());
+            format!("saved state '{}'", name)
+        }
+        ["state", "load", name] => match saved.get(*name) {
+            Some(state) => {
+                *rng = state.clone();
+                format!("loaded state '{}'", name)
+            }
+            None => format!("no saved state '{}'", name),
+        },
+        ["state", "list"] => {
+            let mut names: Vec<&str> = saved.keys().map(String::as_str).collect();
+            names.sort();
+            if names.is_empty() {
+                "no saved states".to_string()
+            } else {
+                names.join("\n")
+            }
+        }
+        _ => format!("unknown command '{}', type 'help' for commands", words.join(" ")),
+    };
+    Ok(Some(reply))
+}
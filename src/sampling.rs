@@ -0,0 +1,820 @@
+// Weighted sampling over streams too long to hold in memory.
+
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+
+use crate::distributions::standard_gumbel;
+use crate::MarsagliaUniRng;
+
+// An item ordered by its key alone.
+#[derive(Clone, Debug)]
+struct Keyed<T> {
+    key: f64,
+    item: T,
+}
+
+impl<T> PartialEq for Keyed<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key.total_cmp(&other.key) == Ordering::Equal
+    }
+}
+
+impl<T> Eq for Keyed<T> {}
+
+impl<T> PartialOrd for Keyed<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T> Ord for Keyed<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.total_cmp(&other.key)
+    }
+}
+
+// Panics unless weight is finite and non-negative.
+fn check_weight(name: &str, weight: f64) {
+    assert!(
+        weight.is_finite() && weight >= 0.0,
+        "{}: weight = {} -- out of range",
+        name,
+        weight
+    );
+}
+
+// A uniform draw in (0, 1], so its logarithm is finite.
+fn open_uniform(rng: &mut MarsagliaUniRng) -> f64 {
+    1.0 - rng.uni() as f64
+}
+
+// Weighted sampling without replacement of k items from a stream, after
+// Efraimidis and Spirakis: item i gets key u^(1/w_i) and the k largest keys
+// are kept (A-Res). Once full, the exponential-jump variant (A-ExpJ) draws
+// how much weight to skip, so only O(k log(n / k)) random numbers are used.
+// Keys are held as logarithms so small weights do not underflow.
+#[derive(Clone, Debug)]
+pub struct WeightedReservoir<T> {
+    k: usize,
+    heap: BinaryHeap<Reverse<Keyed<T>>>,
+    skip: f64, // Weight still to pass over before the next insertion.
+}
+
+impl<T> WeightedReservoir<T> {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k),
+            skip: 0.0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    // Offers one item; items of weight 0 are never selected.
+    // Panics unless weight is finite and non-negative.
+    pub fn push(&mut self, rng: &mut MarsagliaUniRng, item: T, weight: f64) {
+        check_weight("WeightedReservoir::push", weight);
+        if weight == 0.0 || self.k == 0 {
+            return;
+        }
+        if self.heap.len() < self.k {
+            let key = open_uniform(rng).ln() / weight;
+            self.heap.push(Reverse(Keyed { key, item }));
+            if self.heap.len() == self.k {
+                self.draw_skip(rng);
+            }
+            return;
+        }
+        self.skip -= weight;
+        if self.skip > 0.0 {
+            return;
+        }
+        // This item replaces the smallest key; its key is drawn conditioned on beating it.
+        let threshold = self.heap.peek().expect("reservoir is full").0.key;
+        let t = (threshold * weight).exp();
+        let key = (t + (1.0 - t) * rng.uni() as f64).ln() / weight;
+        self.heap.pop();
+        self.heap.push(Reverse(Keyed {
+            key: key.max(threshold),
+            item,
+        }));
+        self.draw_skip(rng);
+    }
+
+    // Offers every (item, weight) pair in turn.
+    pub fn extend<I>(&mut self, rng: &mut MarsagliaUniRng, items: I)
+    where
+        I: IntoIterator<Item = (T, f64)>,
+    {
+        for (item, weight) in items {
+            self.push(rng, item, weight);
+        }
+    }
+
+    // The sampled items, in no particular order.
+    pub fn into_items(self) -> Vec<T> {
+        self.heap.into_iter().map(|Reverse(k)| k.item).collect()
+    }
+
+    fn draw_skip(&mut self, rng: &mut MarsagliaUniRng) {
+        let threshold = self.heap.peek().expect("reservoir is full").0.key;
+        self.skip = open_uniform(rng).ln() / threshold;
+    }
+}
+
+// Priority sampling (Duffield, Lund and Thorup): item i gets priority w_i / u
+// and the k highest are kept. With tau the (k + 1)-th highest priority, each
+// kept item's adjusted weight max(w_i, tau) gives unbiased subset sums.
+#[derive(Clone, Debug)]
+pub struct PrioritySampler<T> {
+    k: usize,
+    // The k + 1 highest priorities seen, holding (weight, item).
+    heap: BinaryHeap<Reverse<Keyed<(f64, T)>>>,
+}
+
+impl<T> PrioritySampler<T> {
+    pub fn new(k: usize) -> Self {
+        Self {
+            k,
+            heap: BinaryHeap::with_capacity(k + 1),
+        }
+    }
+
+    // Offers one item; items of weight 0 are never selected.
+    // Panics unless weight is finite and non-negative.
+    pub fn push(&mut self, rng: &mut MarsagliaUniRng, item: T, weight: f64) {
+        check_weight("PrioritySampler::push", weight);
+        if weight == 0.0 {
+            return;
+        }
+        let key = weight / open_uniform(rng);
+        if self.heap.len() <= self.k {
+            self.heap.push(Reverse(Keyed {
+                key,
+                item: (weight, item),
+            }));
+        } else if key > self.heap.peek().expect("heap is full").0.key {
+            self.heap.pop();
+            self.heap.push(Reverse(Keyed {
+                key,
+                item: (weight, item),
+            }));
+        }
+    }
+
+    // Offers every (item, weight) pair in turn.
+    pub fn extend<I>(&mut self, rng: &mut MarsagliaUniRng, items: I)
+    where
+        I: IntoIterator<Item = (T, f64)>,
+    {
+        for (item, weight) in items {
+            self.push(rng, item, weight);
+        }
+    }
+
+    // The threshold tau, or 0 while no more than k items have been seen.
+    pub fn threshold(&self) -> f64 {
+        if self.heap.len() > self.k {
+            self.heap.peek().expect("heap is full").0.key
+        } else {
+            0.0
+        }
+    }
+
+    // The sampled items with their adjusted weights, in no particular order.
+    pub fn sample(&self) -> Vec<(&T, f64)> {
+        let tau = self.threshold();
+        let mut entries: Vec<&Keyed<(f64, T)>> = self.heap.iter().map(|Reverse(k)| k).collect();
+        if self.heap.len() > self.k {
+            // Drop the entry holding the threshold itself.
+            let lowest = (0..entries.len())
+                .min_by(|a, b| entries[*a].cmp(entries[*b]))
+                .expect("heap is full");
+            entries.swap_remove(lowest);
+        }
+        entries
+            .into_iter()
+            .map(|k| (&k.item.1, k.item.0.max(tau)))
+            .collect()
+    }
+
+    // Unbiased estimate of the total weight of the stream's items matching keep.
+    pub fn estimate<F: FnMut(&T) -> bool>(&self, mut keep: F) -> f64 {
+        self.sample()
+            .into_iter()
+            .filter(|(item, _)| keep(item))
+            .map(|(_, w)| w)
+            .sum()
+    }
+}
+
+// VarOpt_k sampling (Cohen, Duffield, Kaplan, Lund and Thorup, 2009): a
+// sample of k items whose adjusted weights give unbiased subset sums with the
+// least possible variance. Items heavier than the threshold tau are kept with
+// their own weight; the rest each stand for weight tau.
+#[derive(Clone, Debug)]
+pub struct VarOptSampler<T> {
+    k: usize,
+    large: BinaryHeap<Reverse<Keyed<T>>>, // Weight above tau, keyed by weight.
+    small: Vec<T>,                        // Adjusted weight tau each.
+    tau: f64,
+}
+
+impl<T> VarOptSampler<T> {
+    // Panics if k is 0.
+    pub fn new(k: usize) -> Self {
+        assert!(k > 0, "VarOptSampler::new: k must be positive");
+        Self {
+            k,
+            large: BinaryHeap::with_capacity(k + 1),
+            small: Vec::with_capacity(k + 1),
+            tau: 0.0,
+        }
+    }
+
+    // Offers one item; items of weight 0 are never selected.
+    // Panics unless weight is finite and non-negative.
+    pub fn push(&mut self, rng: &mut MarsagliaUniRng, item: T, weight: f64) {
+        check_weight("VarOptSampler::push", weight);
+        if weight == 0.0 {
+            return;
+        }
+        self.large.push(Reverse(Keyed { key: weight, item }));
+        if self.large.len() + self.small.len() <= self.k {
+            return;
+        }
+        // Move the lightest large items into the small set until the new
+        // threshold is no more than the next large weight.
+        let mut moved: Vec<Keyed<T>> = Vec::new();
+        let mut total = self.tau * self.small.len() as f64;
+        while let Some(Reverse(lightest)) = self.large.peek() {
+            let others = (self.small.len() + moved.len()) as f64 - 1.0;
+            if total < others * lightest.key {
+                break;
+            }
+            let Reverse(lightest) = self.large.pop().expect("peeked");
+            total += lightest.key;
+            moved.push(lightest);
+        }
+        let tau = total / (self.small.len() + moved.len() - 1) as f64;
+        // Drop one item: a moved one with probability 1 - w / tau each,
+        // otherwise an old small item uniformly.
+        let mut r = rng.uni() as f64;
+        let mut dropped = None;
+        for (i, m) in moved.iter().enumerate() {
+            r -= 1.0 - m.key / tau;
+            if r < 0.0 {
+                dropped = Some(i);
+                break;
+            }
+        }
+        match dropped {
+            Some(i) => {
+                moved.swap_remove(i);
+            }
+            None if !self.small.is_empty() => {
+                let i = rng.gen_range_u64(0..self.small.len() as u64) as usize;
+                self.small.swap_remove(i);
+            }
+            // Rounding left r just above 0 with no old small items.
+            None => {
+                moved.pop();
+            }
+        }
+        self.small.extend(moved.into_iter().map(|m| m.item));
+        self.tau = tau;
+    }
+
+    // Offers every (item, weight) pair in turn.
+    pub fn extend<I>(&mut self, rng: &mut MarsagliaUniRng, items: I)
+    where
+        I: IntoIterator<Item = (T, f64)>,
+    {
+        for (item, weight) in items {
+            self.push(rng, item, weight);
+        }
+    }
+
+    // The threshold tau, or 0 while no more than k items have been seen.
+    pub fn threshold(&self) -> f64 {
+        self.tau
+    }
+
+    // The sampled items with their adjusted weights, in no particular order.
+    pub fn sample(&self) -> Vec<(&T, f64)> {
+        self.large
+            .iter()
+            .map(|Reverse(k)| (&k.item, k.key))
+            .chain(self.small.iter().map(|item| (item, self.tau)))
+            .collect()
+    }
+
+    // Unbiased estimate of the total weight of the stream's items matching keep.
+    pub fn estimate<F: FnMut(&T) -> bool>(&self, mut keep: F) -> f64 {
+        self.sample()
+            .into_iter()
+            .filter(|(item, _)| keep(item))
+            .map(|(_, w)| w)
+            .sum()
+    }
+}
+
+// Picks index i with probability proportional to exp(log_weights[i]) by
+// taking the argmax of log_weights[i] + Gumbel noise; no normalising is needed.
+// Entries of -inf are never picked. Panics if log_weights is empty, holds
+// NaN or +inf, or is all -inf.
+pub fn gumbel_max_pick(rng: &mut MarsagliaUniRng, log_weights: &[f64]) -> usize {
+    let mut best: Option<(usize, f64)> = None;
+    for (i, &w) in log_weights.iter().enumerate() {
+        assert!(
+            !w.is_nan() && w != f64::INFINITY,
+            "gumbel_max_pick: log weight {} = {} -- out of range",
+            i,
+            w
+        );
+        if w == f64::NEG_INFINITY {
+            continue;
+        }
+        let score = w + standard_gumbel(rng);
+        if best.is_none_or(|(_, b)| score > b) {
+            best = Some((i, score));
+        }
+    }
+    best.expect("gumbel_max_pick: no finite log weight").0
+}
+
+// Starts an exponential clock per rate and returns the first to ring with its
+// time. Index i wins with probability rates[i] / sum and the time is
+// exponential with rate sum, as in Gillespie's direct method. Rates of 0
+// never win. Panics if a rate is negative or not finite, or none is positive.
+pub fn exponential_race(rng: &mut MarsagliaUniRng, rates: &[f64]) -> (usize, f64) {
+    let mut best: Option<(usize, f64)> = None;
+    for (i, &rate) in rates.iter().enumerate() {
+        assert!(
+            rate.is_finite() && rate >= 0.0,
+            "exponential_race: rate {} = {} -- out of range",
+            i,
+            rate
+        );
+        if rate == 0.0 {
+            continue;
+        }
+        let time = -open_uniform(rng).ln() / rate;
+        if best.is_none_or(|(_, t)| time < t) {
+            best = Some((i, time));
+        }
+    }
+    best.expect("exponential_race: no positive rate")
+}
+
+// Picks a token index from logits as language-model decoders do: keep the
+// top_k highest logits, apply softmax at the given temperature, keep the
+// smallest most-probable set whose probability reaches top_p (nucleus
+// sampling), then draw from what remains. Temperature 0 picks the highest
+// logit; ties go to the lower index. Logits of -inf are masked out.
+// Panics if logits is empty, holds NaN or +inf, or is all -inf; if
+// temperature is negative or not finite; if top_k is Some(0); or if top_p is
+// outside (0, 1].
+pub fn sample_logits(
+    rng: &mut MarsagliaUniRng,
+    logits: &[f32],
+    temperature: f32,
+    top_k: Option<usize>,
+    top_p: Option<f32>,
+) -> usize {
+    assert!(
+        temperature.is_finite() && temperature >= 0.0,
+        "sample_logits: temperature = {} -- out of range",
+        temperature
+    );
+    assert!(top_k != Some(0), "sample_logits: top_k must be positive");
+    if let Some(p) = top_p {
+        assert!(
+            p > 0.0 && p <= 1.0,
+            "sample_logits: top_p = {} -- out of range",
+            p
+        );
+    }
+    assert!(
+        logits.iter().all(|l| !l.is_nan() && *l != f32::INFINITY),
+        "sample_logits: logits must not be NaN or +inf"
+    );
+    let mut order: Vec<usize> = (0..logits.len())
+        .filter(|i| logits[*i] != f32::NEG_INFINITY)
+        .collect();
+    assert!(!order.is_empty(), "sample_logits: no finite logit");
+    // Stable, so equal logits keep index order.
+    order.sort_by(|a, b| logits[*b].total_cmp(&logits[*a]));
+    if temperature == 0.0 {
+        return order[0];
+    }
+    order.truncate(top_k.unwrap_or(order.len()));
+
+    let max = logits[order[0]] as f64;
+    let mut probs: Vec<f64> = order
+        .iter()
+        .map(|i| ((logits[*i] as f64 - max) / temperature as f64).exp())
+        .collect();
+    let total: f64 = probs.iter().sum();
+    for p in probs.iter_mut() {
+        *p /= total;
+    }
+    if let Some(top_p) = top_p {
+        let mut cumulative = 0.0;
+        let keep = probs
+            .iter()
+            .position(|p| {
+                cumulative += p;
+                cumulative >= top_p as f64
+            })
+            .map_or(probs.len(), |i| i + 1);
+        probs.truncate(keep);
+    }
+    let mut target = rng.uni() as f64 * probs.iter().sum::<f64>();
+    for (i, p) in probs.iter().enumerate() {
+        if target < *p {
+            return order[i];
+        }
+        target -= p;
+    }
+    // Rounding can leave target just above the last probability.
+    order[probs.len() - 1]
+}
+
+// Sorts values into ascending order of key_fn, putting elements with equal
+// keys in a random order: every order of a tied group is equally likely, and
+// the same seed always gives the same result. Wrap the key in
+// core::cmp::Reverse to sort descending.
+pub fn random_tiebreak_sort<T, K, F>(rng: &mut MarsagliaUniRng, values: &mut [T], key_fn: F)
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    // A random order, then a stable sort, leaves each tied group shuffled.
+    rng.shuffle(values);
+    values.sort_by_key(key_fn);
+}
+
+// Returns the indices of values in ascending order of key_fn, with ties
+// broken at random as random_tiebreak_sort does. With the same generator
+// state, values[argsort[r]] is the element random_tiebreak_sort puts at rank r.
+pub fn argsort_with_random_ties<T, K, F>(
+    rng: &mut MarsagliaUniRng,
+    values: &[T],
+    mut key_fn: F,
+) -> Vec<usize>
+where
+    K: Ord,
+    F: FnMut(&T) -> K,
+{
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    rng.shuffle(&mut order);
+    order.sort_by_key(|i| key_fn(&values[*i]));
+    order
+}
+
+// Everything needed to re-run and check a draw made by draw_with_transcript.
+// The draw is a partial Fisher-Yates shuffle of the positions 0..population
+// with the generator seeded by rinit(seed): at step i, position i is swapped
+// with position swaps[i], drawn by gen_range_u64 from i..population, and the
+// position left at i is the i-th winner.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DrawTranscript {
+    pub seed: i32,           // The rinit seed of the draw.
+    pub population: usize,   // The number of items drawn from.
+    pub k: usize,            // The number of winners.
+    pub swaps: Vec<usize>,   // The position swapped into place at each step.
+    pub winners: Vec<usize>, // Indices of the winners in the items, in draw order.
+}
+
+// Draws k distinct winners from items, each k-subset equally likely and in
+// random order, and returns them with the transcript of the draw. Panics if
+// seed is out of range for rinit or k exceeds the number of items.
+pub fn draw_with_transcript<T: Clone>(
+    seed: i32,
+    items: &[T],
+    k: usize,
+) -> (Vec<T>, DrawTranscript) {
+    let transcript = DrawTranscript::run(seed, items.len(), k);
+    let winners = transcript
+        .winners
+        .iter()
+        .map(|i| items[*i].clone())
+        .collect();
+    (winners, transcript)
+}
+
+impl DrawTranscript {
+    fn run(seed: i32, population: usize, k: usize) -> Self {
+        assert!(
+            k <= population,
+            "draw_with_transcript: k = {}, population = {} -- out of range",
+            k,
+            population
+        );
+        let mut rng = MarsagliaUniRng::unseeded().rinit(seed);
+        // Only the positions moved so far are stored, so the draw takes O(k)
+        // memory however large the population.
+        let mut moved: HashMap<usize, usize> = HashMap::new();
+        let mut swaps = Vec::with_capacity(k);
+        let mut winners = Vec::with_capacity(k);
+        for i in 0..k {
+            let j = rng.gen_range_u64(i as u64..population as u64) as usize;
+            let at_j = moved.get(&j).copied().unwrap_or(j);
+            let at_i = moved.get(&i).copied().unwrap_or(i);
+            moved.insert(j, at_i);
+            swaps.push(j);
+            winners.push(at_j);
+        }
+        Self {
+            seed,
+            population,
+            k,
+            swaps,
+            winners,
+        }
+    }
+
+    // Re-runs the draw from the recorded seed and counts, and checks that it
+    // gives the recorded swaps and winners. False if any field was altered,
+    // or if the seed or counts could not have produced a draw.
+    pub fn verify(&self) -> bool {
+        // Re-running takes time and memory in k alone, and k must match the
+        // recorded draws, so a made-up population cannot make this costly.
+        self.swaps.len() == self.k
+            && self.winners.len() == self.k
+            && (0..=900_000_000).contains(&self.seed)
+            && self.k <= self.population
+            && Self::run(self.seed, self.population, self.k) == *self
+    }
+}
+
+// One line per field, for publishing alongside the result.
+impl std::fmt::Display for DrawTranscript {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let list = |values: &[usize]| {
+            values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        writeln!(f, "unirand draw")?;
+        writeln!(f, "seed {}", self.seed)?;
+        writeln!(f, "population {}", self.population)?;
+        writeln!(f, "k {}", self.k)?;
+        writeln!(f, "swaps {}", list(&self.swaps))?;
+        write!(f, "winners {}", list(&self.winners))
+    }
+}
+
+// Fitness-proportional selection over a dense array of weights, indexed
+// 0..len. The weights sit at the leaves of a flat binary tree of partial
+// sums, so set_weight and sample are O(log n), and set_weights applies a
+// batch of updates recomputing each affected sum once. Sums are recomputed
+// from the leaves on every update, so rounding errors do not build up however
+// many updates are made.
+#[derive(Clone, Debug)]
+pub struct CumulativeSampler {
+    tree: Vec<f64>, // Node n has children 2n and 2n + 1; index i is leaf capacity + i.
+    len: usize,
+}
+
+impl CumulativeSampler {
+    // Builds the tree in O(n). Panics unless every weight is finite and
+    // non-negative.
+    pub fn new(weights: &[f64]) -> Self {
+        let capacity = weights.len().next_power_of_two();
+        let mut tree = vec![0.0; 2 * capacity];
+        for (leaf, w) in tree[capacity..].iter_mut().zip(weights) {
+            check_weight("CumulativeSampler::new", *w);
+            *leaf = *w;
+        }
+        for node in (1..capacity).rev() {
+            tree[node] = tree[2 * node] + tree[2 * node + 1];
+        }
+        Self {
+            tree,
+            len: weights.len(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn capacity(&self) -> usize {
+        self.tree.len() / 2
+    }
+
+    pub fn total_weight(&self) -> f64 {
+        self.tree[1]
+    }
+
+    // Panics if i is not below len.
+    pub fn weight(&self, i: usize) -> f64 {
+        assert!(
+            i < self.len,
+            "CumulativeSampler::weight: index {} -- out of range",
+            i
+        );
+        self.tree[self.capacity() + i]
+    }
+
+    // Appends an index with the given weight, doubling the tree when full.
+    // Panics unless weight is finite and non-negative.
+    pub fn push(&mut self, weight: f64) {
+        check_weight("CumulativeSampler::push", weight);
+        if self.len == self.capacity() && self.len > 0 {
+            let old = self.capacity();
+            let mut tree = vec![0.0; 4 * old];
+            tree[2 * old..3 * old].copy_from_slice(&self.tree[old..]);
+            for node in (1..2 * old).rev() {
+                tree[node] = tree[2 * node] + tree[2 * node + 1];
+            }
+            self.tree = tree;
+        }
+        self.len += 1;
+        self.set_weight(self.len - 1, weight);
+    }
+
+    // Panics if i is not below len, or unless weight is finite and non-negative.
+    pub fn set_weight(&mut self, i: usize, weight: f64) {
+        check_weight("CumulativeSampler::set_weight", weight);
+        assert!(
+            i < self.len,
+            "CumulativeSampler::set_weight: index {} -- out of range",
+            i
+        );
+        let mut node = self.capacity() + i;
+        self.tree[node] = weight;
+        while node > 1 {
+            node /= 2;
+            self.tree[node] = self.tree[2 * node] + self.tree[2 * node + 1];
+        }
+    }
+
+    // Applies (index, weight) updates in order, so a later update of an index
+    // wins, then recomputes each affected sum once, level by level. Panics as
+    // set_weight does, before changing anything.
+    pub fn set_weights(&mut self, updates: &[(usize, f64)]) {
+        for (i, w) in updates {
+            check_weight("CumulativeSampler::set_weights", *w);
+            assert!(
+                *i < self.len,
+                "CumulativeSampler::set_weights: index {} -- out of range",
+                i
+            );
+        }
+        let capacity = self.capacity();
+        let mut nodes: Vec<usize> = updates
+            .iter()
+            .map(|(i, w)| {
+                self.tree[capacity + i] = *w;
+                (capacity + i) / 2
+            })
+            .collect();
+        while !nodes.is_empty() && nodes[0] > 0 {
+            nodes.sort_unstable();
+            nodes.dedup();
+            for node in &mut nodes {
+                self.tree[*node] = self.tree[2 * *node] + self.tree[2 * *node + 1];
+                *node /= 2;
+            }
+        }
+    }
+
+    // Picks index i with probability weight(i) / total_weight, or None if
+    // every weight is zero. Indices of weight zero are never picked.
+    pub fn sample(&self, rng: &mut MarsagliaUniRng) -> Option<usize> {
+        let total = self.total_weight();
+        if total <= 0.0 {
+            return None;
+        }
+        let bits = ((rng.next_word() as u64) << 24) | rng.next_word() as u64;
+        let mut target = bits as f64 / (1u64 << 48) as f64 * total;
+        let mut node = 1;
+        while node < self.capacity() {
+            let (left, right) = (self.tree[2 * node], self.tree[2 * node + 1]);
+            // Rounding can leave target past the last positive weight; never
+            // step into a subtree of weight zero.
+            if left > 0.0 && (target < left || right <= 0.0) {
+                node *= 2;
+            } else {
+                target -= left;
+                node = 2 * node + 1;
+            }
+        }
+        Some(node - self.capacity())
+    }
+}
+
+// A set of weighted items for repeated sampling while the weights change, as
+// in agent-based models. The weights are held in a CumulativeSampler, so
+// insert, remove, set_weight and sample are all O(log n). Items are
+// identified by the id insert returns; ids of removed items are reused.
+#[derive(Clone, Debug)]
+pub struct DynamicWeightedSet<T> {
+    items: Vec<Option<T>>,
+    weights: CumulativeSampler, // Weight of each id, zero for removed ids.
+    free: Vec<usize>,
+    len: usize,
+}
+
+impl<T> Default for DynamicWeightedSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> DynamicWeightedSet<T> {
+    pub fn new() -> Self {
+        Self {
+            items: Vec::new(),
+            weights: CumulativeSampler::new(&[]),
+            free: Vec::new(),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn total_weight(&self) -> f64 {
+        self.weights.total_weight()
+    }
+
+    pub fn get(&self, id: usize) -> Option<&T> {
+        self.items.get(id)?.as_ref()
+    }
+
+    pub fn weight(&self, id: usize) -> Option<f64> {
+        self.get(id)?;
+        Some(self.weights.weight(id))
+    }
+
+    // Adds item with the given weight and returns its id. Panics unless weight
+    // is finite and non-negative.
+    pub fn insert(&mut self, item: T, weight: f64) -> usize {
+        check_weight("DynamicWeightedSet::insert", weight);
+        let id = match self.free.pop() {
+            Some(id) => {
+                self.weights.set_weight(id, weight);
+                self.items[id] = Some(item);
+                id
+            }
+            None => {
+                self.weights.push(weight);
+                self.items.push(Some(item));
+                self.items.len() - 1
+            }
+        };
+        self.len += 1;
+        id
+    }
+
+    // Removes and returns the item with this id, if there is one.
+    pub fn remove(&mut self, id: usize) -> Option<T> {
+        let item = self.items.get_mut(id)?.take()?;
+        self.weights.set_weight(id, 0.0);
+        self.free.push(id);
+        self.len -= 1;
+        Some(item)
+    }
+
+    // Changes an item's weight. Panics if there is no item with this id, or
+    // unless weight is finite and non-negative.
+    pub fn set_weight(&mut self, id: usize, weight: f64) {
+        check_weight("DynamicWeightedSet::set_weight", weight);
+        assert!(
+            self.get(id).is_some(),
+            "DynamicWeightedSet::set_weight: no item with id {}",
+            id
+        );
+        self.weights.set_weight(id, weight);
+    }
+
+    // Picks an item with probability proportional to its weight, returning its
+    // id and the item, or None if every weight is zero. Items of weight zero are
+    // never picked.
+    pub fn sample(&self, rng: &mut MarsagliaUniRng) -> Option<(usize, &T)> {
+        let id = self.weights.sample(rng)?;
+        Some((id, self.get(id).expect("positive weights belong to items")))
+    }
+}
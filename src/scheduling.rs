@@ -0,0 +1,107 @@
+// Randomised assignment and timetabling helpers, reproducible per seed.
+
+use std::collections::HashSet;
+
+use crate::sampling::argsort_with_random_ties;
+use crate::{MarsagliaUniRng, RejectionLimitError};
+
+// Assigns each of the candidates to one of the slots, where slots[s] is the
+// capacity of slot s and no candidate c is put in slot s for a pair (c, s)
+// in forbidden_pairs. Each attempt places candidates most-constrained first,
+// in random order among equals, each into a random allowed slot among those
+// with the most room left, so loads stay even; an attempt that strands a
+// candidate is restarted from scratch, up to max_attempts in all. Returns the
+// slot of each candidate. Panics if the slots cannot hold every candidate, a
+// forbidden pair is out of range, or max_attempts is 0.
+pub fn constrained_assignment(
+    rng: &mut MarsagliaUniRng,
+    candidates: usize,
+    slots: &[usize],
+    forbidden_pairs: &[(usize, usize)],
+    max_attempts: u64,
+) -> Result<Vec<usize>, RejectionLimitError> {
+    assert!(
+        slots.iter().sum::<usize>() >= candidates,
+        "constrained_assignment: {} candidates exceed the slot capacity",
+        candidates
+    );
+    assert!(
+        forbidden_pairs
+            .iter()
+            .all(|(c, s)| *c < candidates && *s < slots.len()),
+        "constrained_assignment: forbidden pair out of range"
+    );
+    assert!(
+        max_attempts > 0,
+        "constrained_assignment: max_attempts must be positive"
+    );
+    let forbidden: HashSet<(usize, usize)> = forbidden_pairs.iter().copied().collect();
+    let allowed = |c: usize, s: usize| slots[s] > 0 && !forbidden.contains(&(c, s));
+    let options: Vec<usize> = (0..candidates)
+        .map(|c| (0..slots.len()).filter(|s| allowed(c, *s)).count())
+        .collect();
+
+    'attempt: for _ in 0..max_attempts {
+        let order = argsort_with_random_ties(rng, &options, |n| *n);
+        let mut room = slots.to_vec();
+        let mut assignment = vec![0; candidates];
+        for c in order {
+            let roomiest = (0..slots.len())
+                .filter(|s| allowed(c, *s))
+                .map(|s| room[s])
+                .max()
+                .unwrap_or(0);
+            if roomiest == 0 {
+                continue 'attempt;
+            }
+            let best: Vec<usize> = (0..slots.len())
+                .filter(|s| allowed(c, *s) && room[*s] == roomiest)
+                .collect();
+            let s = best[rng.gen_range_u64(0..best.len() as u64) as usize];
+            room[s] -= 1;
+            assignment[c] = s;
+        }
+        return Ok(assignment);
+    }
+    Err(RejectionLimitError {
+        limit: max_attempts,
+    })
+}
+
+// Shifts each event of a schedule, given as start slots, by an independent
+// uniform whole number of slots in -max_shift..=max_shift, to simulate
+// schedule noise or seed a timetabling search.
+pub fn perturb_schedule(rng: &mut MarsagliaUniRng, slots: &[i64], max_shift: u32) -> Vec<i64> {
+    let width = 2 * max_shift as u64 + 1;
+    slots
+        .iter()
+        .map(|slot| slot + rng.gen_range_u64(0..width) as i64 - max_shift as i64)
+        .collect()
+}
+
+// Picks a slot in 0..n_slots uniformly from those not in busy, or None if
+// every slot is busy.
+pub fn pick_free_slot(rng: &mut MarsagliaUniRng, n_slots: usize, busy: &[usize]) -> Option<usize> {
+    pick_free_slots(rng, n_slots, busy, 1).map(|picked| picked[0])
+}
+
+// Picks k distinct slots in 0..n_slots that are not in busy, each such set
+// equally likely and in random order, or None if fewer than k are free.
+pub fn pick_free_slots(
+    rng: &mut MarsagliaUniRng,
+    n_slots: usize,
+    busy: &[usize],
+    k: usize,
+) -> Option<Vec<usize>> {
+    let busy: HashSet<usize> = busy.iter().copied().collect();
+    let mut free: Vec<usize> = (0..n_slots).filter(|s| !busy.contains(s)).collect();
+    if free.len() < k {
+        return None;
+    }
+    for i in 0..k {
+        let j = rng.gen_range_u64(i as u64..free.len() as u64) as usize;
+        free.swap(i, j);
+    }
+    free.truncate(k);
+    Some(free)
+}
@@ -0,0 +1,10 @@
+// Building blocks for simulations and numerical experiments. dp and optimise are
+// experimental and need the unstable feature.
+
+#[cfg(feature = "unstable")]
+pub mod dp;
+#[cfg(feature = "unstable")]
+pub mod optimise;
+pub mod physics;
+pub mod rotation;
+pub mod sensitivity;
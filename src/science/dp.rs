@@ -0,0 +1,78 @@
+// Noise for prototyping differentially private pipelines.
+//
+// Research and testing quality only: NOT production differential privacy.
+// Samples come from 24-bit uniforms through floating-point arithmetic, which
+// is known to leak information through the gaps and rounding of the output
+// (Mironov, 2012), and nothing here resists timing side channels. Use a
+// vetted DP library for releasing real data.
+
+use crate::MarsagliaUniRng;
+
+// Laplace noise with mean 0 and the given scale b, density exp(-|x| / b) / 2b.
+// Panics unless scale is finite and positive.
+pub fn laplace(rng: &mut MarsagliaUniRng, scale: f64) -> f64 {
+    assert!(
+        scale.is_finite() && scale > 0.0,
+        "laplace: scale = {} -- out of range",
+        scale
+    );
+    // uni can return 0, so offset it by half an output step: u is then in
+    // (-0.5, 0.5), symmetric about 0, so 1 - 2|u| is in (0, 1) and the logarithm
+    // is finite.
+    let u = rng.uni() as f64 + 0.5 / 16_777_216.0 - 0.5;
+    -scale * u.signum() * (1.0 - 2.0 * u.abs()).ln()
+}
+
+// Discrete Gaussian on the integers, P(x) proportional to exp(-x^2 / 2 sigma^2),
+// by rejection from a discrete Laplace as in Canonne, Kamath and Steinke (2020).
+// Panics unless sigma is finite and positive.
+pub fn discrete_gaussian_dp(rng: &mut MarsagliaUniRng, sigma: f64) -> i64 {
+    assert!(
+        sigma.is_finite() && sigma > 0.0,
+        "discrete_gaussian_dp: sigma = {} -- out of range",
+        sigma
+    );
+    let t = sigma.floor() + 1.0;
+    let sigma_sq = sigma * sigma;
+    loop {
+        let y = discrete_laplace(rng, t);
+        let d = y.unsigned_abs() as f64 - sigma_sq / t;
+        if (rng.uni() as f64) < (-d * d / (2.0 * sigma_sq)).exp() {
+            return y;
+        }
+    }
+}
+
+// Adds Laplace noise of scale sensitivity / epsilon to each value, the Laplace
+// mechanism for an epsilon-DP release of a query with that L1 sensitivity.
+// Panics unless epsilon and sensitivity are finite and positive.
+pub fn add_laplace_noise(
+    rng: &mut MarsagliaUniRng,
+    values: &mut [f64],
+    epsilon: f64,
+    sensitivity: f64,
+) {
+    assert!(
+        epsilon.is_finite() && epsilon > 0.0 && sensitivity.is_finite() && sensitivity > 0.0,
+        "add_laplace_noise: epsilon = {}, sensitivity = {} -- out of range",
+        epsilon,
+        sensitivity
+    );
+    let scale = sensitivity / epsilon;
+    for v in values {
+        *v += laplace(rng, scale);
+    }
+}
+
+// P(x) proportional to exp(-|x| / t): a geometric magnitude with a random sign,
+// rejecting negative zero so that 0 is not counted twice.
+fn discrete_laplace(rng: &mut MarsagliaUniRng, t: f64) -> i64 {
+    let log_q = -1.0 / t;
+    loop {
+        let magnitude = ((1.0 - rng.uni() as f64).ln() / log_q).floor() as i64;
+        let negative = rng.uni() < 0.5;
+        if !(negative && magnitude == 0) {
+            return if negative { -magnitude } else { magnitude };
+        }
+    }
+}
@@ -0,0 +1,106 @@
+// Helpers for optimisers driven by the generator.
+
+use crate::MarsagliaUniRng;
+
+// Returns n_starts starting points spread over the box given by bounds, as a
+// row-major n_starts x bounds.len() matrix. Each input's range is cut into
+// n_starts equal strata and every stratum is used exactly once (a Latin
+// hypercube), with each point placed uniformly at random within its stratum.
+pub fn multi_start_points(
+    rng: &mut MarsagliaUniRng,
+    n_starts: usize,
+    bounds: &[(f64, f64)],
+) -> Vec<f64> {
+    let dim = bounds.len();
+    let mut points = vec![0.0; n_starts * dim];
+    let mut strata: Vec<usize> = (0..n_starts).collect();
+    for (i, &(low, high)) in bounds.iter().enumerate() {
+        rng.shuffle(&mut strata);
+        let width = (high - low) / n_starts as f64;
+        for (point, &stratum) in points.chunks_exact_mut(dim).zip(&strata) {
+            point[i] = low + width * (stratum as f64 + rng.uni() as f64);
+        }
+    }
+    points
+}
+
+// Returns, for each target index of a population, three distinct indices that
+// all differ from the target, as used by differential evolution mutation.
+// Panics if pop_size < 4.
+pub fn de_mutation_triples(rng: &mut MarsagliaUniRng, pop_size: usize) -> Vec<[usize; 3]> {
+    assert!(
+        pop_size >= 4,
+        "de_mutation_triples: pop_size = {} -- need at least 4",
+        pop_size
+    );
+    let mut pick = |taken: &[usize]| loop {
+        let r = rng.gen_range_u64(0..pop_size as u64) as usize;
+        if !taken.contains(&r) {
+            return r;
+        }
+    };
+    (0..pop_size)
+        .map(|target| {
+            let r1 = pick(&[target]);
+            let r2 = pick(&[target, r1]);
+            let r3 = pick(&[target, r1, r2]);
+            [r1, r2, r3]
+        })
+        .collect()
+}
+
+// Writes mean + sigma * L z into out, where z is standard normal and L is the
+// lower-triangular Cholesky factor of the covariance (row-major dim x dim, as
+// returned by cholesky). This is the CMA-ES style step x = m + sigma * N(0, C).
+// Panics if the slice lengths do not match.
+pub fn gaussian_step(
+    rng: &mut MarsagliaUniRng,
+    mean: &[f64],
+    cholesky_lower: &[f64],
+    sigma: f64,
+    out: &mut [f64],
+) {
+    let dim = mean.len();
+    assert!(
+        out.len() == dim && cholesky_lower.len() == dim * dim,
+        "gaussian_step: expected {} outputs and a {}x{} factor",
+        dim,
+        dim,
+        dim
+    );
+    let mut z = vec![0.0f32; dim];
+    rng.fill_normal(&mut z, 0.0, 1.0);
+    for (i, x) in out.iter_mut().enumerate() {
+        let row = &cholesky_lower[i * dim..i * dim + i + 1];
+        let lz: f64 = row.iter().zip(&z).map(|(l, z)| l * *z as f64).sum();
+        *x = mean[i] + sigma * lz;
+    }
+}
+
+// Returns the lower-triangular Cholesky factor L of a symmetric positive-definite
+// row-major dim x dim matrix (cov = L L^T), or None if it is not positive definite.
+pub fn cholesky(cov: &[f64], dim: usize) -> Option<Vec<f64>> {
+    assert_eq!(
+        cov.len(),
+        dim * dim,
+        "cholesky: expected a {}x{} matrix",
+        dim,
+        dim
+    );
+    let mut l = vec![0.0; dim * dim];
+    for i in 0..dim {
+        for j in 0..=i {
+            let dot: f64 = (0..j).map(|k| l[i * dim + k] * l[j * dim + k]).sum();
+            let v = cov[i * dim + j] - dot;
+            if i == j {
+                if v <= 0.0 || !v.is_finite() {
+                    return None;
+                }
+                l[i * dim + i] = v.sqrt();
+            } else {
+                l[i * dim + j] = v / l[j * dim + j];
+            }
+        }
+    }
+    Some(l)
+}
@@ -0,0 +1,351 @@
+// Initial conditions for particle simulations, in reduced units with k_B = 1.
+
+use crate::{MarsagliaUniRng, RejectionLimitError};
+
+// Rejection tries per particle before uniform_positions_in_box gives up.
+const MAX_ATTEMPTS: u64 = 10_000;
+
+// Draws n velocities whose components are normal with variance temperature / mass.
+// Panics unless temperature and mass are finite, temperature >= 0 and mass > 0.
+pub fn maxwell_boltzmann_velocities(
+    rng: &mut MarsagliaUniRng,
+    n: usize,
+    temperature: f64,
+    mass: f64,
+) -> Vec<[f64; 3]> {
+    assert!(
+        temperature.is_finite() && temperature >= 0.0 && mass.is_finite() && mass > 0.0,
+        "maxwell_boltzmann_velocities: temperature = {}, mass = {} -- out of range",
+        temperature,
+        mass
+    );
+    let sigma = (temperature / mass).sqrt();
+    let mut normals = vec![0.0f32; 3 * n];
+    rng.fill_normal_pairs(&mut normals);
+    normals
+        .chunks_exact(3)
+        .map(|v| [v[0], v[1], v[2]].map(|x| sigma * x as f64))
+        .collect()
+}
+
+// Draws n positions uniformly in [0, box_dims[i]) along each axis. With
+// min_separation, a candidate closer than that to an earlier particle is
+// redrawn; None means some particle could not be placed within MAX_ATTEMPTS
+// tries, so the box is too crowded. Distances ignore periodic images.
+// Panics unless every box dimension is finite and positive.
+pub fn uniform_positions_in_box(
+    rng: &mut MarsagliaUniRng,
+    n: usize,
+    box_dims: [f64; 3],
+    min_separation: Option<f64>,
+) -> Option<Vec<[f64; 3]>> {
+    uniform_positions_in_box_limited(rng, n, box_dims, min_separation, MAX_ATTEMPTS).ok()
+}
+
+// As uniform_positions_in_box with a caller's cap on the tries per particle,
+// reporting a crowded box as RejectionLimitError. Panics if max_attempts is 0.
+pub fn uniform_positions_in_box_limited(
+    rng: &mut MarsagliaUniRng,
+    n: usize,
+    box_dims: [f64; 3],
+    min_separation: Option<f64>,
+    max_attempts: u64,
+) -> Result<Vec<[f64; 3]>, RejectionLimitError> {
+    assert!(
+        box_dims.iter().all(|d| d.is_finite() && *d > 0.0),
+        "uniform_positions_in_box: box_dims = {:?} -- out of range",
+        box_dims
+    );
+    assert!(
+        max_attempts > 0,
+        "uniform_positions_in_box: max_attempts must be positive"
+    );
+    let min_sq = min_separation.map(|d| d * d);
+    let mut positions: Vec<[f64; 3]> = Vec::with_capacity(n);
+    for _ in 0..n {
+        let mut placed = false;
+        for _ in 0..max_attempts {
+            let p = box_dims.map(|d| d * rng.uni() as f64);
+            let clear = match min_sq {
+                None => true,
+                Some(min_sq) => positions.iter().all(|q| {
+                    let d2: f64 = (0..3).map(|i| (p[i] - q[i]) * (p[i] - q[i])).sum();
+                    d2 >= min_sq
+                }),
+            };
+            if clear {
+                positions.push(p);
+                placed = true;
+                break;
+            }
+        }
+        if !placed {
+            return Err(RejectionLimitError {
+                limit: max_attempts,
+            });
+        }
+    }
+    Ok(positions)
+}
+
+// Ising spins of +1 or -1 on a periodic lattice, stored row-major with the
+// last dimension varying fastest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpinLattice {
+    pub dims: Vec<usize>,
+    pub spins: Vec<i8>,
+}
+
+// Sets each site up with probability p_up.
+// Panics if dims is empty or has a zero, or p_up is outside [0, 1].
+pub fn random_spin_lattice(rng: &mut MarsagliaUniRng, dims: &[usize], p_up: f32) -> SpinLattice {
+    assert!(
+        (0.0..=1.0).contains(&p_up),
+        "random_spin_lattice: p_up = {} -- out of range",
+        p_up
+    );
+    let spins = (0..lattice_len(dims))
+        .map(|_| if rng.uni() < p_up { 1 } else { -1 })
+        .collect();
+    SpinLattice {
+        dims: dims.to_vec(),
+        spins,
+    }
+}
+
+impl SpinLattice {
+    // Mean spin, in [-1, 1].
+    pub fn magnetisation(&self) -> f64 {
+        self.spins.iter().map(|s| *s as f64).sum::<f64>() / self.spins.len() as f64
+    }
+
+    // E = -coupling * sum over neighbour pairs of s_i s_j - field * sum of s_i.
+    pub fn energy(&self, coupling: f64, field: f64) -> f64 {
+        let mut pairs = 0i64;
+        for site in 0..self.spins.len() {
+            // Count each pair once through the forward neighbour on every axis.
+            for_each_axis(&self.dims, site, |_, forward| {
+                pairs += (self.spins[site] * self.spins[forward]) as i64;
+            });
+        }
+        let total: i64 = self.spins.iter().map(|s| *s as i64).sum();
+        -coupling * pairs as f64 - field * total as f64
+    }
+
+    // One Metropolis sweep at inverse temperature beta: each site in turn is
+    // flipped with probability min(1, exp(-beta * dE)). Returns the flips made.
+    // Experimental: needs the unstable feature.
+    #[cfg(feature = "unstable")]
+    pub fn metropolis_sweep(
+        &mut self,
+        rng: &mut MarsagliaUniRng,
+        beta: f64,
+        coupling: f64,
+        field: f64,
+    ) -> usize {
+        let mut flips = 0;
+        for site in 0..self.spins.len() {
+            let mut neighbours = 0i32;
+            for_each_axis(&self.dims, site, |backward, forward| {
+                neighbours += (self.spins[backward] + self.spins[forward]) as i32;
+            });
+            let s = self.spins[site] as f64;
+            let delta = 2.0 * s * (coupling * neighbours as f64 + field);
+            if delta <= 0.0 || (rng.uni() as f64) < (-beta * delta).exp() {
+                self.spins[site] = -self.spins[site];
+                flips += 1;
+            }
+        }
+        flips
+    }
+}
+
+fn lattice_len(dims: &[usize]) -> usize {
+    assert!(
+        !dims.is_empty() && dims.iter().all(|d| *d > 0),
+        "lattice dims = {:?} -- need at least one dimension, none zero",
+        dims
+    );
+    dims.iter().product()
+}
+
+// Calls f with the periodic backward and forward neighbours of site along each axis.
+fn for_each_axis(dims: &[usize], site: usize, mut f: impl FnMut(usize, usize)) {
+    let mut stride = 1;
+    for &len in dims.iter().rev() {
+        let coord = (site / stride) % len;
+        let base = site - coord * stride;
+        let backward = base + (coord + len - 1) % len * stride;
+        let forward = base + (coord + 1) % len * stride;
+        f(backward, forward);
+        stride *= len;
+    }
+}
+
+// Site percolation: each site of an open-boundary lattice is occupied with probability p.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SiteLattice {
+    pub dims: Vec<usize>,
+    pub occupied: Vec<bool>,
+}
+
+// Bond percolation: each bond between neighbouring sites of an open-boundary
+// lattice is open with probability p. Bond site * dims.len() + axis joins
+// site to its forward neighbour along axis and is never open on the far face.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BondLattice {
+    pub dims: Vec<usize>,
+    pub open: Vec<bool>,
+}
+
+// Panics if dims is empty or has a zero, or p is outside [0, 1].
+pub fn site_percolation(rng: &mut MarsagliaUniRng, dims: &[usize], p: f32) -> SiteLattice {
+    assert!(
+        (0.0..=1.0).contains(&p),
+        "site_percolation: p = {} -- out of range",
+        p
+    );
+    let occupied = (0..lattice_len(dims)).map(|_| rng.uni() < p).collect();
+    SiteLattice {
+        dims: dims.to_vec(),
+        occupied,
+    }
+}
+
+// Panics if dims is empty or has a zero, or p is outside [0, 1].
+pub fn bond_percolation(rng: &mut MarsagliaUniRng, dims: &[usize], p: f32) -> BondLattice {
+    assert!(
+        (0.0..=1.0).contains(&p),
+        "bond_percolation: p = {} -- out of range",
+        p
+    );
+    let mut open = vec![false; lattice_len(dims) * dims.len()];
+    for site in 0..open.len() / dims.len() {
+        for_each_forward(dims, site, |axis, _| {
+            open[site * dims.len() + axis] = rng.uni() < p;
+        });
+    }
+    BondLattice {
+        dims: dims.to_vec(),
+        open,
+    }
+}
+
+impl SiteLattice {
+    // Labels occupied sites by cluster, with labels 0, 1, ... in order of each
+    // cluster's first site. Empty sites are None.
+    pub fn clusters(&self) -> Vec<Option<usize>> {
+        let mut sets = UnionFind::new(self.occupied.len());
+        for site in 0..self.occupied.len() {
+            if self.occupied[site] {
+                for_each_forward(&self.dims, site, |_, next| {
+                    if self.occupied[next] {
+                        sets.union(site, next);
+                    }
+                });
+            }
+        }
+        let mut by_root = vec![None; self.occupied.len()];
+        let mut next = 0;
+        (0..self.occupied.len())
+            .map(|site| {
+                if !self.occupied[site] {
+                    return None;
+                }
+                let root = sets.find(site);
+                Some(*by_root[root].get_or_insert_with(|| {
+                    next += 1;
+                    next - 1
+                }))
+            })
+            .collect()
+    }
+}
+
+impl BondLattice {
+    // Labels every site by the cluster its open bonds connect it to, with
+    // labels 0, 1, ... in order of each cluster's first site.
+    pub fn clusters(&self) -> Vec<usize> {
+        let n = self.open.len() / self.dims.len();
+        let mut sets = UnionFind::new(n);
+        for site in 0..n {
+            for_each_forward(&self.dims, site, |axis, next| {
+                if self.open[site * self.dims.len() + axis] {
+                    sets.union(site, next);
+                }
+            });
+        }
+        sets.labels()
+    }
+}
+
+// Disjoint sets over 0..n with path halving and union by size.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    // Joins the sets holding a and b; returns false if they were already one.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        true
+    }
+
+    // Size of the set holding x.
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let root = self.find(x);
+        self.size[root]
+    }
+
+    // One label per element, numbered 0, 1, ... in order of first appearance.
+    pub fn labels(&mut self) -> Vec<usize> {
+        let mut by_root = vec![usize::MAX; self.parent.len()];
+        let mut next = 0;
+        (0..self.parent.len())
+            .map(|x| {
+                let root = self.find(x);
+                if by_root[root] == usize::MAX {
+                    by_root[root] = next;
+                    next += 1;
+                }
+                by_root[root]
+            })
+            .collect()
+    }
+}
+
+// Calls f with each axis and the forward neighbour along it, skipping the far face.
+fn for_each_forward(dims: &[usize], site: usize, mut f: impl FnMut(usize, usize)) {
+    let mut stride = 1;
+    for (axis, &len) in dims.iter().enumerate().rev() {
+        if (site / stride) % len + 1 < len {
+            f(axis, site + stride);
+        }
+        stride *= len;
+    }
+}
@@ -0,0 +1,92 @@
+// Seeded random rotations of embedding sets.
+//
+// A uniformly random (Haar) orthogonal matrix is built as a product of dim
+// Householder reflections, following Stewart (1980), and stored as its
+// reflection vectors: O(dim^2) memory, and no dim x dim matrix is formed.
+// Rows are transformed in blocks so each reflector is reused while it is
+// in cache.
+
+use crate::distributions::Normal;
+use crate::MarsagliaUniRng;
+
+// Rows transformed together per pass over the reflectors.
+const BLOCK_ROWS: usize = 64;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RandomRotation {
+    dim: usize,
+    // Reflector k acts on coordinates k.. as x -> sign * (x - 2 v (v . x) / (v . v)).
+    reflectors: Vec<(Vec<f64>, f64)>,
+}
+
+impl RandomRotation {
+    // Panics if dim is 0.
+    pub fn new(rng: &mut MarsagliaUniRng, dim: usize) -> Self {
+        assert!(dim > 0, "RandomRotation::new: dim must be positive");
+        let normal = Normal::new(0.0, 1.0);
+        let mut x = vec![0.0f32; dim];
+        let reflectors = (0..dim)
+            .map(|k| {
+                let x = &mut x[..dim - k];
+                normal.fill(rng, x);
+                let mut v: Vec<f64> = x.iter().map(|a| *a as f64).collect();
+                let norm = v.iter().map(|a| a * a).sum::<f64>().sqrt();
+                // v = x + s |x| e_1 avoids cancellation; the reflection then sends
+                // e_1 to -s x / |x|, so scaling by -s sends it to the uniform x / |x|.
+                let s = if v[0] < 0.0 { -1.0 } else { 1.0 };
+                v[0] += s * norm;
+                (v, -s)
+            })
+            .collect();
+        Self { dim, reflectors }
+    }
+
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    // Rotates each dim-long row of data in place.
+    // Panics if data.len() is not a multiple of dim.
+    pub fn apply(&self, data: &mut [f32]) {
+        self.transform(data, (0..self.dim).rev());
+    }
+
+    // Undoes apply; every reflector is its own inverse, so they run in reverse order.
+    pub fn apply_inverse(&self, data: &mut [f32]) {
+        self.transform(data, 0..self.dim);
+    }
+
+    fn transform(&self, data: &mut [f32], order: impl Iterator<Item = usize> + Clone) {
+        assert!(
+            data.len().is_multiple_of(self.dim),
+            "RandomRotation: data.len() = {} -- not a multiple of dim = {}",
+            data.len(),
+            self.dim
+        );
+        for block in data.chunks_mut(BLOCK_ROWS * self.dim) {
+            for k in order.clone() {
+                let (v, sign) = &self.reflectors[k];
+                let vv: f64 = v.iter().map(|a| a * a).sum();
+                if vv == 0.0 {
+                    // Only possible for an all-zero draw; treat as the identity.
+                    continue;
+                }
+                for row in block.chunks_exact_mut(self.dim) {
+                    let tail = &mut row[k..];
+                    let dot: f64 = tail.iter().zip(v).map(|(a, b)| *a as f64 * b).sum();
+                    let f = 2.0 * dot / vv;
+                    for (a, b) in tail.iter_mut().zip(v) {
+                        *a = (sign * (*a as f64 - f * b)) as f32;
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Rotates each dim-long row of data by one seeded random orthogonal transform.
+// Keep a RandomRotation instead to rotate later data the same way or undo it.
+// Panics if dim is 0 or data.len() is not a multiple of dim.
+pub fn random_rotation_apply(rng: &mut MarsagliaUniRng, data: &mut [f32], dim: usize) {
+    RandomRotation::new(rng, dim).apply(data);
+}
@@ -0,0 +1,152 @@
+// Fallible seeding, for seeds that come from user input.
+//
+// try_rinit accepts exactly the seeds rinit does and try_rstart checks the
+// ranges Marsaglia and Zaman give for the four rstart seeds: i, j and k in
+// 1..=178, not all 1, and l in 0..=168. rstart itself still takes any seeds,
+// as the original port did.
+//
+// from_u64 and from_bytes accept any seed and map it to rstart seeds as follows;
+// the mapping is part of the stable API and will not change between releases.
+// from_u64(s) steps a SplitMix64 state from s by 0x9e3779b97f4a7c15 four times;
+// with z1..z4 the mixed outputs, i = 1 + z1 % 178, j = 1 + z2 % 178,
+// k = 1 + z3 % 178 and l = z4 % 169, except that k becomes 2 if i, j and k all
+// come out as 1. from_bytes(b) is from_u64(h), where h starts as the length of
+// b and absorbs each 8-byte little-endian chunk c (the last zero-padded) as
+// h = mix64(h ^ c) with mix64 the SplitMix64 finaliser. from_seed_str(s) is
+// from_bytes of the UTF-8 bytes of s.
+
+use crate::generator::mix64;
+use crate::MarsagliaUniRng;
+
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15; // The SplitMix64 increment.
+
+// The seed component that was out of range.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SeedError {
+    Ijkl(i32), // The rinit seed is outside 0..=900_000_000.
+    I(i32),    // The first rstart seed is outside 1..=178.
+    J(i32),    // The second rstart seed is outside 1..=178.
+    K(i32),    // The third rstart seed is outside 1..=178.
+    L(i32),    // The fourth rstart seed is outside 0..=168.
+    AllOnes,   // The first three rstart seeds are all 1.
+}
+
+impl core::fmt::Display for SeedError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            SeedError::Ijkl(v) => write!(f, "seed ijkl = {} is outside 0..=900000000", v),
+            SeedError::I(v) => write!(f, "seed i = {} is outside 1..=178", v),
+            SeedError::J(v) => write!(f, "seed j = {} is outside 1..=178", v),
+            SeedError::K(v) => write!(f, "seed k = {} is outside 1..=178", v),
+            SeedError::L(v) => write!(f, "seed l = {} is outside 0..=168", v),
+            SeedError::AllOnes => f.write_str("seeds i, j and k must not all be 1"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SeedError {}
+
+// Checks four rstart seeds against their ranges.
+pub(crate) fn check_rstart(i: i32, j: i32, k: i32, l: i32) -> Result<(), SeedError> {
+    let seed = 1..=178;
+    if !seed.contains(&i) {
+        Err(SeedError::I(i))
+    } else if !seed.contains(&j) {
+        Err(SeedError::J(j))
+    } else if !seed.contains(&k) {
+        Err(SeedError::K(k))
+    } else if !(0..=168).contains(&l) {
+        Err(SeedError::L(l))
+    } else if i == 1 && j == 1 && k == 1 {
+        Err(SeedError::AllOnes)
+    } else {
+        Ok(())
+    }
+}
+
+// Decomposes a single rinit seed into the four rstart seeds.
+pub(crate) fn decompose(ijkl: i32) -> Result<(i32, i32, i32, i32), SeedError> {
+    if !(0..=900_000_000).contains(&ijkl) {
+        return Err(SeedError::Ijkl(ijkl));
+    }
+    let ij = ijkl / 30082;
+    let kl = ijkl - (30082 * ij);
+    let i = ((ij / 177) % 177) + 2;
+    let j = (ij % 177) + 2;
+    let k = ((kl / 169) % 178) + 1;
+    let l = kl % 169;
+    check_rstart(i, j, k, l)?;
+    Ok((i, j, k, l))
+}
+
+// The four rstart seeds from_u64 uses for seed.
+fn u64_seeds(seed: u64) -> (i32, i32, i32, i32) {
+    let mut state = seed;
+    let mut next = |modulus: u64| {
+        state = state.wrapping_add(GOLDEN_GAMMA);
+        (mix64(state) % modulus) as i32
+    };
+    let (i, j, mut k, l) = (1 + next(178), 1 + next(178), 1 + next(178), next(169));
+    if i == 1 && j == 1 && k == 1 {
+        k = 2;
+    }
+    (i, j, k, l)
+}
+
+// Folds a byte string into the u64 from_bytes seeds with.
+pub(crate) fn fold_bytes(bytes: &[u8]) -> u64 {
+    let mut h = bytes.len() as u64;
+    for chunk in bytes.chunks(8) {
+        let mut word = [0u8; 8];
+        word[..chunk.len()].copy_from_slice(chunk);
+        h = mix64(h ^ u64::from_le_bytes(word));
+    }
+    h
+}
+
+impl MarsagliaUniRng {
+    // A generator seeded from any u64, by the stable mapping described above.
+    pub fn from_u64(seed: u64) -> Self {
+        let (i, j, k, l) = u64_seeds(seed);
+        Self::unseeded().rstart(i, j, k, l)
+    }
+
+    // A generator seeded from a byte array, such as a hash or key.
+    pub fn from_bytes<const N: usize>(bytes: [u8; N]) -> Self {
+        Self::from_u64(fold_bytes(&bytes))
+    }
+
+    // A generator seeded from a label, such as a run name, so the same label
+    // always gives the same stream.
+    pub fn from_seed_str(label: &str) -> Self {
+        Self::from_u64(fold_bytes(label.as_bytes()))
+    }
+
+    // A generator seeded with rinit(ijkl) in one step. Panics on a bad seed, as rinit does.
+    pub fn from_seed(ijkl: i32) -> Self {
+        Self::unseeded().rinit(ijkl)
+    }
+
+    // As from_seed, returning an error instead of panicking on a bad seed.
+    pub fn try_from_seed(ijkl: i32) -> Result<Self, SeedError> {
+        Self::unseeded().try_rinit(ijkl)
+    }
+
+    // As rinit, returning an error instead of panicking on a bad seed.
+    // On error the generator is left unchanged.
+    pub fn try_rinit(&mut self, ijkl: i32) -> Result<(), SeedError> {
+        let (i, j, k, l) = decompose(ijkl)?;
+        self.rstart(i, j, k, l);
+        Ok(())
+    }
+
+    // As rstart, after checking each seed is in range.
+    // On error the generator is left unchanged.
+    pub fn try_rstart(&mut self, i: i32, j: i32, k: i32, l: i32) -> Result<(), SeedError> {
+        check_rstart(i, j, k, l)?;
+        self.rstart(i, j, k, l);
+        Ok(())
+    }
+}
@@ -0,0 +1,109 @@
+// A smoke test over the seed space for the `seed-scan` subcommand.
+//
+// Every stride-th seed in 0..=900_000_000 is seeded with rinit and drawn from;
+// a seed fails if seeding or drawing panics, an output falls outside [0, 1),
+// the state breaks check_invariants, or the outputs are all equal. The seeds
+// are split across threads in interleaved order, so each thread covers the
+// whole space and a slow region cannot leave one thread with all the work.
+
+use std::panic;
+use std::thread;
+
+use unirand::MarsagliaUniRng;
+
+use crate::flag_value;
+
+const MAX_SEED: i32 = 900_000_000;
+
+// Runs `unirand seed-scan` with the arguments following the subcommand.
+pub fn run(args: &[String]) -> Result<(), String> {
+    let mut stride = 9_001;
+    let mut draws = 100;
+    let mut threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let mut i = 0;
+    while i < args.len() {
+        let value = flag_value(args, i)?;
+        match args[i].as_str() {
+            "--stride" => stride = parse_count(value, "--stride")?,
+            "--draws" => draws = parse_count(value, "--draws")?,
+            "--threads" => threads = parse_count(value, "--threads")?,
+            other => return Err(format!("unknown seed-scan option '{}'", other)),
+        }
+        i += 2;
+    }
+
+    // Panics are caught per seed and reported below, so silence the default hook.
+    panic::set_hook(Box::new(|_| {}));
+    let failures = scan(stride as i32, draws, threads);
+    let _ = panic::take_hook();
+
+    let seeds = MAX_SEED as usize / stride + 1;
+    for (seed, reason) in &failures {
+        println!("seed {}: {}", seed, reason);
+    }
+    println!("{} seeds checked, {} failed", seeds, failures.len());
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} seeds failed the scan", failures.len()))
+    }
+}
+
+// Parses a positive count, keeping strides within the seed range.
+fn parse_count(text: &str, flag: &str) -> Result<usize, String> {
+    match text.parse::<usize>() {
+        Ok(n) if n > 0 && n <= MAX_SEED as usize => Ok(n),
+        _ => Err(format!("{} '{}' must be a positive integer", flag, text)),
+    }
+}
+
+// Checks every stride-th seed on the given number of threads, returning the
+// failures in seed order.
+fn scan(stride: i32, draws: usize, threads: usize) -> Vec<(i32, String)> {
+    let mut failures: Vec<(i32, String)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..threads)
+            .map(|t| {
+                scope.spawn(move || {
+                    let step = stride as i64 * threads as i64;
+                    let mut seed = stride as i64 * t as i64;
+                    let mut found = Vec::new();
+                    while seed <= MAX_SEED as i64 {
+                        if let Err(reason) = check_seed(seed as i32, draws) {
+                            found.push((seed as i32, reason));
+                        }
+                        seed += step;
+                    }
+                    found
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|w| w.join().unwrap_or_default())
+            .collect()
+    });
+    failures.sort_unstable_by_key(|(seed, _)| *seed);
+    failures
+}
+
+// Seeds one generator and checks its first outputs and final state.
+fn check_seed(seed: i32, draws: usize) -> Result<(), String> {
+    let outcome = panic::catch_unwind(|| {
+        let mut rng = MarsagliaUniRng::unseeded().rinit(seed);
+        let first = rng.uni();
+        let mut constant = true;
+        for n in 0..draws {
+            let x = if n == 0 { first } else { rng.uni() };
+            if !(0.0..1.0).contains(&x) {
+                return Err(format!("draw {} = {} is outside [0, 1)", n, x));
+            }
+            constant &= x == first;
+        }
+        if constant && draws > 1 {
+            return Err(format!("the first {} draws are all {}", draws, first));
+        }
+        rng.check_invariants()
+            .map_err(|v| format!("state invariant broken: {:?}", v))
+    });
+    outcome.unwrap_or_else(|_| Err("panicked".to_string()))
+}
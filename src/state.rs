@@ -0,0 +1,157 @@
+// A fixed-size binary snapshot of the whole generator state.
+//
+// Layout, STATE_LEN bytes with every integer little-endian by default, or all
+// big-endian when written with Endian::Big:
+//
+//   bytes 0..4       magic "UNRS"
+//   bytes 4..6       format version (1), u16, which also tells the byte order
+//   byte  6          indexing: 0 for Indexing::Port, 1 for Indexing::Ranmar
+//   byte  7          1 if the generator has been seeded, else 0
+//   bytes 8..400     the 98 table entries, u32 each
+//   bytes 400..412   the correction c, its delta cd and its modulus cm, u32 each
+//   bytes 412..416   the lag indices ui and uj, u16 each
+//   bytes 416..432   the four rstart seeds, i32 each
+//   bytes 432..440   values generated since seeding, u64
+//
+// Every table entry and correction is a multiple of 2^-24 in [0, 1) and is
+// stored as its 24-bit numerator, so the snapshot is exact and a port in
+// another language can restore it without reproducing f32 bit patterns. A
+// snapshot of an unseeded generator restores as an unseeded generator with
+// the same indexing; the remaining fields are then ignored.
+
+use crate::{Endian, Indexing, InvariantViolation, MarsagliaUniRng, LEN_U, TWO_24};
+
+const MAGIC: [u8; 4] = *b"UNRS";
+pub const STATE_VERSION: u16 = 1;
+pub const STATE_LEN: usize = 440;
+
+// Why restore_state rejected a snapshot.
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StateError {
+    BadMagic,                      // The snapshot does not start with "UNRS".
+    UnsupportedVersion(u16),       // The snapshot has a format version this build cannot read.
+    BadField(usize),               // The byte at this offset holds no valid indexing or flag.
+    Invariant(InvariantViolation), // The restored state would not be well formed.
+}
+
+impl core::fmt::Display for StateError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            StateError::BadMagic => f.write_str("not a generator state snapshot"),
+            StateError::UnsupportedVersion(v) => {
+                write!(f, "unsupported state snapshot version {}", v)
+            }
+            StateError::BadField(offset) => {
+                write!(f, "invalid state snapshot byte at offset {}", offset)
+            }
+            StateError::Invariant(e) => write!(f, "invalid state snapshot: {}", e),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for StateError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            StateError::Invariant(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+// The 24-bit numerator of a multiple of 2^-24, and back.
+fn numerator(x: f32) -> u32 {
+    (x * TWO_24) as u32
+}
+
+fn fraction(n: u32) -> f32 {
+    n as f32 / TWO_24
+}
+
+impl MarsagliaUniRng {
+    // Captures the whole state in the layout above, little-endian.
+    pub fn save_state(&self) -> [u8; STATE_LEN] {
+        self.save_state_endian(Endian::Little)
+    }
+
+    // As save_state, with every integer in the given byte order.
+    pub fn save_state_endian(&self, endian: Endian) -> [u8; STATE_LEN] {
+        let mut out = [0u8; STATE_LEN];
+        out[0..4].copy_from_slice(&MAGIC);
+        out[4..6].copy_from_slice(&endian.order(STATE_VERSION.to_le_bytes()));
+        out[6] = self.indexing as u8;
+        out[7] = self.initialized as u8;
+        let words = self
+            .uni_u
+            .iter()
+            .chain([&self.uni_c, &self.uni_cd, &self.uni_cm]);
+        for (chunk, x) in out[8..412].chunks_exact_mut(4).zip(words) {
+            chunk.copy_from_slice(&endian.order(numerator(*x).to_le_bytes()));
+        }
+        out[412..414].copy_from_slice(&endian.order((self.uni_ui as u16).to_le_bytes()));
+        out[414..416].copy_from_slice(&endian.order((self.uni_uj as u16).to_le_bytes()));
+        for (chunk, seed) in out[416..432].chunks_exact_mut(4).zip(self.seeds) {
+            chunk.copy_from_slice(&endian.order(seed.to_le_bytes()));
+        }
+        out[432..440].copy_from_slice(&endian.order(self.draws.to_le_bytes()));
+        out
+    }
+
+    // Rebuilds the generator a snapshot from save_state or save_state_endian
+    // was taken of, in either byte order, which continues its sequence
+    // exactly. Seeded snapshots must pass check_invariants; unseeded
+    // ones are accepted only with the legacy feature.
+    pub fn restore_state(bytes: &[u8; STATE_LEN]) -> Result<Self, StateError> {
+        if bytes[0..4] != MAGIC {
+            return Err(StateError::BadMagic);
+        }
+        let version = [bytes[4], bytes[5]];
+        let endian = if u16::from_le_bytes(version) == STATE_VERSION {
+            Endian::Little
+        } else if u16::from_be_bytes(version) == STATE_VERSION {
+            Endian::Big
+        } else {
+            return Err(StateError::UnsupportedVersion(u16::from_le_bytes(version)));
+        };
+        let indexing = match bytes[6] {
+            0 => Indexing::Port,
+            1 => Indexing::Ranmar,
+            _ => return Err(StateError::BadField(6)),
+        };
+        let mut rng = Self::blank(indexing);
+        // Only the legacy API can hand out an unseeded generator, so only
+        // it accepts an unseeded snapshot.
+        match bytes[7] {
+            0 if cfg!(feature = "legacy") => return Ok(rng),
+            1 => rng.initialized = true,
+            _ => return Err(StateError::BadField(7)),
+        }
+
+        let u32_at = |at: usize| {
+            u32::from_le_bytes(endian.order([
+                bytes[at],
+                bytes[at + 1],
+                bytes[at + 2],
+                bytes[at + 3],
+            ]))
+        };
+        let u16_at = |at: usize| u16::from_le_bytes(endian.order([bytes[at], bytes[at + 1]]));
+        for (i, x) in rng.uni_u.iter_mut().enumerate() {
+            *x = fraction(u32_at(8 + 4 * i));
+        }
+        let corrections = 8 + 4 * LEN_U;
+        rng.uni_c = fraction(u32_at(corrections));
+        rng.uni_cd = fraction(u32_at(corrections + 4));
+        rng.uni_cm = fraction(u32_at(corrections + 8));
+        rng.uni_ui = u16_at(412) as usize;
+        rng.uni_uj = u16_at(414) as usize;
+        for (i, seed) in rng.seeds.iter_mut().enumerate() {
+            *seed = u32_at(416 + 4 * i) as i32;
+        }
+        rng.draws =
+            u64::from_le_bytes(endian.order(bytes[432..440].try_into().expect("8-byte field")));
+        rng.check_invariants().map_err(StateError::Invariant)?;
+        Ok(rng)
+    }
+}
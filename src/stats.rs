@@ -0,0 +1,199 @@
+// Statistical checks for code layered over the generator, and classic
+// probability experiments with known answers.
+
+use std::collections::HashSet;
+
+use crate::MarsagliaUniRng;
+
+// Result of a chi-square goodness-of-fit test.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ChiSquare {
+    pub statistic: f64,
+    pub dof: usize,
+    pub p_value: f64, // Chance of a statistic at least this large under the null.
+}
+
+// Uniformity tests of a shuffle over trials runs.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShuffleUniformity {
+    // Element-by-final-position counts against trials / n each.
+    pub positions: ChiSquare,
+    // Counts of whole permutations against trials / n! each; only run for
+    // n <= 6 with at least five expected per permutation.
+    pub permutations: Option<ChiSquare>,
+}
+
+impl ShuffleUniformity {
+    // True if no test rejects uniformity at significance alpha.
+    pub fn passes(&self, alpha: f64) -> bool {
+        self.positions.p_value >= alpha && self.permutations.is_none_or(|t| t.p_value >= alpha)
+    }
+}
+
+// Runs shuffler trials times on 0..n, starting from the identity each time,
+// and chi-square tests where each element ends up and, for small n, which
+// permutation results. Panics if n < 2, trials is 0, or the shuffler returns
+// something other than a permutation.
+pub fn verify_shuffle_uniformity<F>(mut shuffler: F, n: usize, trials: usize) -> ShuffleUniformity
+where
+    F: FnMut(&mut [usize]),
+{
+    assert!(
+        n >= 2 && trials > 0,
+        "verify_shuffle_uniformity: need n >= 2 and trials > 0"
+    );
+    let perms = (n <= 6).then(|| (1..=n).product::<usize>());
+    let test_perms = perms.filter(|p| trials >= 5 * p);
+    let mut positions = vec![0u64; n * n];
+    let mut perm_counts = vec![0u64; test_perms.unwrap_or(0)];
+    let mut items: Vec<usize> = Vec::with_capacity(n);
+    let mut seen = vec![false; n];
+    for _ in 0..trials {
+        items.clear();
+        items.extend(0..n);
+        shuffler(&mut items);
+        seen.fill(false);
+        for (pos, &item) in items.iter().enumerate() {
+            assert!(
+                item < n && !seen[item],
+                "verify_shuffle_uniformity: shuffler did not return a permutation"
+            );
+            seen[item] = true;
+            positions[item * n + pos] += 1;
+        }
+        if test_perms.is_some() {
+            perm_counts[lehmer_rank(&items)] += 1;
+        }
+    }
+    ShuffleUniformity {
+        positions: chi_square(&positions, trials as f64 / n as f64, (n - 1) * (n - 1)),
+        permutations: test_perms.map(|p| chi_square(&perm_counts, trials as f64 / p as f64, p - 1)),
+    }
+}
+
+// Upper tail P(X >= statistic) of the chi-square distribution with dof degrees of freedom.
+pub fn chi_square_p_value(statistic: f64, dof: usize) -> f64 {
+    gamma_q(dof as f64 / 2.0, statistic / 2.0)
+}
+
+fn chi_square(counts: &[u64], expected: f64, dof: usize) -> ChiSquare {
+    let statistic = counts
+        .iter()
+        .map(|c| (*c as f64 - expected) * (*c as f64 - expected) / expected)
+        .sum();
+    ChiSquare {
+        statistic,
+        dof,
+        p_value: chi_square_p_value(statistic, dof),
+    }
+}
+
+// Index of a permutation of 0..n in lexicographic order.
+fn lehmer_rank(perm: &[usize]) -> usize {
+    let mut rank = 0;
+    for (i, &p) in perm.iter().enumerate() {
+        let smaller_after = perm[i + 1..].iter().filter(|q| **q < p).count();
+        rank = rank * (perm.len() - i) + smaller_after;
+    }
+    rank
+}
+
+// Regularised upper incomplete gamma Q(a, x), by series below a + 1 and a
+// continued fraction above (Numerical Recipes, 6.2).
+fn gamma_q(a: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 1.0;
+    }
+    let prefix = (-x + a * x.ln() - ln_gamma(a)).exp();
+    if x < a + 1.0 {
+        let (mut term, mut sum, mut ap) = (1.0 / a, 1.0 / a, a);
+        while term.abs() > sum.abs() * 1e-15 {
+            ap += 1.0;
+            term *= x / ap;
+            sum += term;
+        }
+        1.0 - sum * prefix
+    } else {
+        const TINY: f64 = 1e-300;
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / TINY;
+        let mut d = 1.0 / b;
+        let mut h = d;
+        for i in 1..10_000 {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            d = if d.abs() < TINY { TINY } else { d };
+            c = b + an / c;
+            c = if c.abs() < TINY { TINY } else { c };
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+            if (delta - 1.0).abs() < 1e-15 {
+                break;
+            }
+        }
+        prefix * h
+    }
+}
+
+// Lanczos approximation (g = 7, n = 9), for x >= 0.5.
+fn ln_gamma(x: f64) -> f64 {
+    const COEF: [f64; 9] = [
+        0.999_999_999_999_809_9,
+        676.520_368_121_885_1,
+        -1_259.139_216_722_402_8,
+        771.323_428_777_653_1,
+        -176.615_029_162_140_6,
+        12.507_343_278_686_905,
+        -0.138_571_095_265_720_12,
+        9.984_369_578_019_572e-6,
+        1.505_632_735_149_311_6e-7,
+    ];
+    let x = x - 1.0;
+    let t = x + 7.5;
+    let series = COEF[1..]
+        .iter()
+        .enumerate()
+        .fold(COEF[0], |acc, (i, c)| acc + c / (x + i as f64 + 1.0));
+    0.5 * (2.0 * std::f64::consts::PI).ln() + (x + 0.5) * t.ln() - t + series.ln()
+}
+
+// Draws uniformly from n coupon types until every type has been seen and
+// returns the number of draws taken; its mean is n * H(n), about n ln n.
+// Panics if n is 0 or above 2^48.
+pub fn coupon_collector_trials(rng: &mut MarsagliaUniRng, n: u64) -> u64 {
+    assert!(
+        n > 0 && n <= 1 << 48,
+        "coupon_collector_trials: n = {} -- out of range",
+        n
+    );
+    let mut seen = vec![false; n as usize];
+    let (mut missing, mut draws) = (n, 0);
+    while missing > 0 {
+        let coupon = rng.gen_range_u64(0..n) as usize;
+        draws += 1;
+        if !seen[coupon] {
+            seen[coupon] = true;
+            missing -= 1;
+        }
+    }
+    draws
+}
+
+// Draws n birthdays uniformly from d days and reports whether two coincide.
+// For hash sizing, d is the number of hash values and n the number of keys.
+// Panics if d is 0.
+pub fn birthday_collision_trial(rng: &mut MarsagliaUniRng, n: u64, d: u64) -> bool {
+    assert!(
+        d > 0,
+        "birthday_collision_trial: d = {} -- out of range",
+        d
+    );
+    if n > d {
+        return true;
+    }
+    // A collision is expected within about sqrt(d) draws, so grow the set as needed.
+    let mut seen = HashSet::new();
+    (0..n).any(|_| !seen.insert(rng.gen_range_u64(0..d)))
+}
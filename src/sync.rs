@@ -0,0 +1,63 @@
+// A generator that threads can share, for one global reproducible stream.
+//
+// SyncMarsagliaUniRng holds the generator behind a Mutex, so it is Send and
+// Sync and can be stored in an Arc. Each call takes the lock once, so values
+// are handed out in one sequence, but which thread gets which value depends
+// on scheduling; draw batches with fill_f32 or with to cut locking costs. A
+// panic inside with cannot leave the generator part-way through a step, so a
+// poisoned lock is recovered rather than propagated.
+
+use std::sync::{Mutex, MutexGuard};
+
+use crate::MarsagliaUniRng;
+
+#[derive(Debug)]
+pub struct SyncMarsagliaUniRng {
+    rng: Mutex<MarsagliaUniRng>,
+}
+
+impl SyncMarsagliaUniRng {
+    pub fn new(rng: MarsagliaUniRng) -> Self {
+        Self {
+            rng: Mutex::new(rng),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MarsagliaUniRng> {
+        self.rng
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    // Returns the next value of the shared stream, as uni would.
+    pub fn uni(&self) -> f32 {
+        self.lock().uni()
+    }
+
+    // Fills dest with consecutive values of the shared stream under one lock.
+    pub fn fill_f32(&self, dest: &mut [f32]) {
+        self.lock().fill_f32(dest);
+    }
+
+    // Runs f with the generator locked, so its draws are consecutive.
+    pub fn with<T>(&self, f: impl FnOnce(&mut MarsagliaUniRng) -> T) -> T {
+        f(&mut self.lock())
+    }
+
+    // Returns a copy of the current state, for checkpointing.
+    pub fn snapshot(&self) -> MarsagliaUniRng {
+        self.lock().clone()
+    }
+
+    pub fn into_inner(self) -> MarsagliaUniRng {
+        self.rng
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl From<MarsagliaUniRng> for SyncMarsagliaUniRng {
+    fn from(rng: MarsagliaUniRng) -> Self {
+        Self::new(rng)
+    }
+}
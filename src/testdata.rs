@@ -0,0 +1,18 @@
+// Reproducible inputs for testing and benchmarking other software. arrays,
+// corrupt, sat and unicode work without std, with slice-filling forms that
+// need no allocator.
+
+pub mod arrays;
+#[cfg(feature = "std")]
+pub mod bio;
+#[cfg(feature = "std")]
+pub mod boolean;
+pub mod corrupt;
+#[cfg(feature = "std")]
+pub mod datasets;
+#[cfg(feature = "std")]
+pub mod filetree;
+pub mod sat;
+pub mod unicode;
+#[cfg(feature = "std")]
+pub mod workload;
@@ -0,0 +1,77 @@
+// Input arrays for benchmarking sorting and searching, each described by a (pattern, seed) pair.
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use crate::MarsagliaUniRng;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ArrayPattern {
+    // Independent uniform u32 values.
+    Random,
+    // 0..len in order, then this many swaps of two random positions.
+    NearlySorted { swaps: usize },
+    // Values drawn uniformly from 0..distinct.
+    FewUnique { distinct: u32 },
+    // Repeating ramps 0, 1, ..., period - 1, each value plus a random 0..=noise.
+    Sawtooth { period: usize, noise: u32 },
+    // About this fraction of values repeat a random earlier value.
+    Duplicates { ratio: f32 },
+}
+
+// Builds the array for pattern and seed. The same pair always gives the same array.
+// Panics on a seed rinit rejects, distinct or period of 0, or a ratio outside [0, 1].
+#[cfg(feature = "alloc")]
+pub fn adversarial_array(pattern: ArrayPattern, len: usize, seed: i32) -> Vec<u32> {
+    let mut values = vec![0; len];
+    fill_adversarial_array(pattern, seed, &mut values);
+    values
+}
+
+// Fills out with the array adversarial_array(pattern, out.len(), seed) returns,
+// without allocating.
+pub fn fill_adversarial_array(pattern: ArrayPattern, seed: i32, out: &mut [u32]) {
+    let mut rng = MarsagliaUniRng::unseeded().rinit(seed);
+    let len = out.len();
+    match pattern {
+        ArrayPattern::Random => out.iter_mut().for_each(|x| *x = rng.next_u32()),
+        ArrayPattern::NearlySorted { swaps } => {
+            for (i, x) in out.iter_mut().enumerate() {
+                *x = i as u32;
+            }
+            if len > 1 {
+                for _ in 0..swaps {
+                    let i = rng.gen_range_u64(0..len as u64) as usize;
+                    let j = rng.gen_range_u64(0..len as u64) as usize;
+                    out.swap(i, j);
+                }
+            }
+        }
+        ArrayPattern::FewUnique { distinct } => {
+            assert!(distinct > 0, "adversarial_array: distinct must be positive");
+            out.iter_mut()
+                .for_each(|x| *x = rng.gen_range_u32(0..distinct));
+        }
+        ArrayPattern::Sawtooth { period, noise } => {
+            assert!(period > 0, "adversarial_array: period must be positive");
+            for (i, x) in out.iter_mut().enumerate() {
+                let ramp = (i % period) as u32;
+                *x = ramp.saturating_add(rng.gen_range_u32(0..noise.saturating_add(1)));
+            }
+        }
+        ArrayPattern::Duplicates { ratio } => {
+            assert!(
+                (0.0..=1.0).contains(&ratio),
+                "adversarial_array: ratio = {} -- out of range",
+                ratio
+            );
+            for filled in 0..len {
+                out[filled] = if filled > 0 && rng.uni() < ratio {
+                    out[rng.gen_range_u64(0..filled as u64) as usize]
+                } else {
+                    rng.next_u32()
+                };
+            }
+        }
+    }
+}
@@ -0,0 +1,199 @@
+// Simulated DNA and sequencing reads for testing bioinformatics tools.
+
+use crate::MarsagliaUniRng;
+
+const BASES: [u8; 4] = *b"ACGT";
+
+// One simulated read and where it was taken from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SimulatedRead {
+    pub start: usize,     // Offset of the read in the genome.
+    pub sequence: String, // The read, including any substitution errors.
+    pub errors: usize,    // Number of bases substituted.
+}
+
+// Draws len bases over ACGT where each base is G or C with probability gc_content.
+// Panics if gc_content is outside [0, 1].
+pub fn random_dna(rng: &mut MarsagliaUniRng, len: usize, gc_content: f32) -> String {
+    assert!(
+        (0.0..=1.0).contains(&gc_content),
+        "random_dna: gc_content = {} -- out of range",
+        gc_content
+    );
+    (0..len)
+        .map(|_| {
+            let gc = rng.uni() < gc_content;
+            let first = rng.uni() < 0.5;
+            match (gc, first) {
+                (true, true) => 'G',
+                (true, false) => 'C',
+                (false, true) => 'A',
+                (false, false) => 'T',
+            }
+        })
+        .collect()
+}
+
+// Takes n reads of read_len bases from uniformly random offsets in genome.
+// Each base is independently replaced by one of the other three with
+// probability error_rate. Panics if genome is not ASCII, is shorter than
+// read_len, or error_rate is outside [0, 1].
+pub fn random_reads(
+    rng: &mut MarsagliaUniRng,
+    genome: &str,
+    n: usize,
+    read_len: usize,
+    error_rate: f32,
+) -> Vec<SimulatedRead> {
+    assert!(genome.is_ascii(), "random_reads: genome must be ASCII");
+    assert!(
+        read_len <= genome.len(),
+        "random_reads: read_len = {} -- longer than the genome",
+        read_len
+    );
+    assert!(
+        (0.0..=1.0).contains(&error_rate),
+        "random_reads: error_rate = {} -- out of range",
+        error_rate
+    );
+    let genome = genome.as_bytes();
+    let starts = (genome.len() - read_len + 1) as u64;
+    (0..n)
+        .map(|_| {
+            let start = rng.gen_range_u64(0..starts) as usize;
+            let mut errors = 0;
+            let sequence = genome[start..start + read_len]
+                .iter()
+                .map(|&base| {
+                    if rng.uni() >= error_rate {
+                        return base as char;
+                    }
+                    errors += 1;
+                    let others: Vec<u8> = BASES
+                        .iter()
+                        .copied()
+                        .filter(|b| *b != base.to_ascii_uppercase())
+                        .collect();
+                    others[rng.uni_below(others.len() as u32) as usize] as char
+                })
+                .collect();
+            SimulatedRead {
+                start,
+                sequence,
+                errors,
+            }
+        })
+        .collect()
+}
+
+// How random_binary_tree grows its topology.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TreeModel {
+    // Pure-birth: repeatedly split a uniformly chosen leaf.
+    Yule,
+    // Uniform over rooted binary topologies with labelled leaves: each new
+    // leaf is attached to a uniformly chosen edge, including one above the root.
+    Uniform,
+}
+
+// How branch lengths are drawn.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BranchLengths {
+    Fixed(f64),
+    // Exponential with this mean.
+    Exponential { mean: f64 },
+    // Uniform over [min, max).
+    Uniform { min: f64, max: f64 },
+}
+
+// A rooted binary tree as a parent array. Nodes 0..n_leaves are the leaves,
+// the rest internal; the root's parent is None and its branch length 0.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PhyloTree {
+    pub parent: Vec<Option<usize>>,
+    pub branch_length: Vec<f64>,
+}
+
+impl PhyloTree {
+    pub fn root(&self) -> usize {
+        self.parent
+            .iter()
+            .position(|p| p.is_none())
+            .expect("a tree has a root")
+    }
+}
+
+// Draws a tree with n_leaves leaves. Panics if n_leaves is 0 or lengths has a
+// negative or non-finite parameter.
+pub fn random_binary_tree(
+    rng: &mut MarsagliaUniRng,
+    n_leaves: usize,
+    model: TreeModel,
+    lengths: BranchLengths,
+) -> PhyloTree {
+    assert!(n_leaves > 0, "random_binary_tree: need at least one leaf");
+    let valid = match lengths {
+        BranchLengths::Fixed(len) => len.is_finite() && len >= 0.0,
+        BranchLengths::Exponential { mean } => mean.is_finite() && mean >= 0.0,
+        BranchLengths::Uniform { min, max } => {
+            min.is_finite() && max.is_finite() && 0.0 <= min && min <= max
+        }
+    };
+    assert!(
+        valid,
+        "random_binary_tree: lengths = {:?} -- out of range",
+        lengths
+    );
+
+    let mut parent: Vec<Option<usize>> = vec![None];
+    match model {
+        TreeModel::Yule => {
+            let mut tips = vec![0];
+            while tips.len() < n_leaves {
+                let tip = tips.swap_remove(rng.gen_range_u64(0..tips.len() as u64) as usize);
+                for _ in 0..2 {
+                    tips.push(parent.len());
+                    parent.push(Some(tip));
+                }
+            }
+        }
+        TreeModel::Uniform => {
+            for _ in 1..n_leaves {
+                let below = rng.gen_range_u64(0..parent.len() as u64) as usize;
+                let joint = parent.len();
+                parent.push(parent[below]);
+                parent[below] = Some(joint);
+                parent.push(Some(joint));
+            }
+        }
+    }
+
+    // Renumber so the leaves come first, each group keeping its build order.
+    let mut is_leaf = vec![true; parent.len()];
+    for p in parent.iter().flatten() {
+        is_leaf[*p] = false;
+    }
+    let mut order: Vec<usize> = (0..parent.len()).collect();
+    order.sort_by_key(|node| !is_leaf[*node]);
+    let mut new_id = vec![0; parent.len()];
+    for (id, node) in order.iter().enumerate() {
+        new_id[*node] = id;
+    }
+    let parent: Vec<Option<usize>> = order
+        .iter()
+        .map(|node| parent[*node].map(|p| new_id[p]))
+        .collect();
+    let branch_length = parent
+        .iter()
+        .map(|p| match (p, lengths) {
+            (None, _) => 0.0,
+            (_, BranchLengths::Fixed(len)) => len,
+            (_, BranchLengths::Exponential { mean }) => -mean * (1.0 - rng.uni() as f64).ln(),
+            (_, BranchLengths::Uniform { min, max }) => min + (max - min) * rng.uni() as f64,
+        })
+        .collect();
+    PhyloTree {
+        parent,
+        branch_length,
+    }
+}
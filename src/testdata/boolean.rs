@@ -0,0 +1,122 @@
+// Random Boolean functions as bit-packed truth tables, for hardware and EDA test generation.
+
+use std::io::Read;
+
+use crate::{MarsagliaUniRng, RngReader};
+
+// Truth table of a function of n_inputs variables. The output for the input
+// assignment x, with input k as bit k of x, is bit x % 64 of bits[x / 64];
+// unused high bits of the last word are 0.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct TruthTable {
+    pub n_inputs: u32,
+    pub bits: Vec<u64>,
+}
+
+impl TruthTable {
+    // Number of rows, 2^n_inputs.
+    pub fn rows(&self) -> u64 {
+        1 << self.n_inputs
+    }
+
+    pub fn eval(&self, x: u64) -> bool {
+        assert!(
+            x < self.rows(),
+            "TruthTable::eval: x = {} -- out of range",
+            x
+        );
+        self.bits[(x / 64) as usize] >> (x % 64) & 1 == 1
+    }
+
+    // Number of inputs mapped to true.
+    pub fn ones(&self) -> u64 {
+        self.bits.iter().map(|w| w.count_ones() as u64).sum()
+    }
+
+    fn empty(n_inputs: u32) -> Self {
+        assert!(
+            n_inputs <= 32,
+            "truth table: n_inputs = {} -- out of range",
+            n_inputs
+        );
+        Self {
+            n_inputs,
+            bits: vec![0; (1usize << n_inputs).div_ceil(64)],
+        }
+    }
+
+    fn set(&mut self, x: u64) {
+        self.bits[(x / 64) as usize] |= 1 << (x % 64);
+    }
+
+    fn mask_tail(&mut self) {
+        if self.n_inputs < 6 {
+            self.bits[0] &= (1 << self.rows()) - 1;
+        }
+    }
+}
+
+// Every output independent and equally likely, taken from the generator's
+// byte stream as little-endian words. Panics if n_inputs > 32.
+pub fn random_truth_table(rng: &mut MarsagliaUniRng, n_inputs: u32) -> TruthTable {
+    let mut table = TruthTable::empty(n_inputs);
+    let mut bytes = RngReader::new(rng.clone());
+    let mut word = [0u8; 8];
+    for w in table.bits.iter_mut() {
+        bytes
+            .read_exact(&mut word)
+            .expect("the generator never runs dry");
+        *w = u64::from_le_bytes(word);
+    }
+    *rng = bytes.into_inner();
+    table.mask_tail();
+    table
+}
+
+// Each output true independently with probability p_one.
+// Panics if n_inputs > 32 or p_one is outside [0, 1].
+pub fn random_truth_table_biased(
+    rng: &mut MarsagliaUniRng,
+    n_inputs: u32,
+    p_one: f32,
+) -> TruthTable {
+    assert!(
+        (0.0..=1.0).contains(&p_one),
+        "random_truth_table_biased: p_one = {} -- out of range",
+        p_one
+    );
+    let mut table = TruthTable::empty(n_inputs);
+    for x in 0..table.rows() {
+        if rng.uni() < p_one {
+            table.set(x);
+        }
+    }
+    table
+}
+
+// Exactly ones outputs true, the set chosen uniformly.
+// Panics if n_inputs > 32 or ones > 2^n_inputs.
+pub fn random_truth_table_with_ones(
+    rng: &mut MarsagliaUniRng,
+    n_inputs: u32,
+    ones: u64,
+) -> TruthTable {
+    assert!(
+        n_inputs <= 32 && ones <= 1 << n_inputs,
+        "random_truth_table_with_ones: n_inputs = {}, ones = {} -- out of range",
+        n_inputs,
+        ones
+    );
+    let mut table = TruthTable::empty(n_inputs);
+    // Floyd's algorithm: a uniform ones-subset of the rows in ones draws.
+    let rows = table.rows();
+    for j in rows - ones..rows {
+        let t = rng.gen_range_u64(0..j + 1);
+        if table.eval(t) {
+            table.set(j);
+        } else {
+            table.set(t);
+        }
+    }
+    table
+}
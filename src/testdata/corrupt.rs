@@ -0,0 +1,115 @@
+// Reproducible damage to binary data, for testing storage formats and codecs
+// against corruption. The helpers work in place; flip_random_bits_with_scratch
+// and corrupt_regions need no allocator.
+
+#[cfg(feature = "alloc")]
+use alloc::vec;
+
+use crate::generator::mix64;
+use crate::MarsagliaUniRng;
+
+// Marks a free slot of the flip_random_bits scratch table; no bit position is this large.
+const EMPTY: u64 = u64::MAX;
+
+// How long each region overwritten by corrupt_regions is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegionLength {
+    // Every region is this many bytes.
+    Fixed(usize),
+    // Lengths uniform in min..=max.
+    Uniform { min: usize, max: usize },
+}
+
+// Flips exactly n distinct bits of data, each set of n bit positions equally
+// likely, using a scratch table of 2n positions. Panics if n exceeds the
+// number of bits.
+#[cfg(feature = "alloc")]
+pub fn flip_random_bits(rng: &mut MarsagliaUniRng, data: &mut [u8], n: usize) {
+    flip_random_bits_with_scratch(rng, data, n, &mut vec![0; 2 * n]);
+}
+
+// As flip_random_bits, without allocating. Positions are chosen by Floyd's
+// algorithm, one draw per flip, with scratch as an open-addressing set of the
+// positions chosen so far; it needs room for at least 2n of them, and only
+// that much of it is used. Panics if n exceeds the number of bits or scratch
+// is shorter than 2n.
+pub fn flip_random_bits_with_scratch(
+    rng: &mut MarsagliaUniRng,
+    data: &mut [u8],
+    n: usize,
+    scratch: &mut [u64],
+) {
+    let bits = data.len() as u64 * 8;
+    assert!(
+        n as u64 <= bits,
+        "flip_random_bits: n = {} -- out of range",
+        n
+    );
+    assert!(
+        scratch.len() >= 2 * n,
+        "flip_random_bits: scratch.len() = {} -- shorter than 2n",
+        scratch.len()
+    );
+    let table = &mut scratch[..2 * n];
+    table.fill(EMPTY);
+    // Adds position to the set, returning false if it was already there.
+    let mut insert = |position: u64| {
+        let len = table.len();
+        let mut slot = ((mix64(position) as u128 * len as u128) >> 64) as usize;
+        loop {
+            if table[slot] == position {
+                return false;
+            }
+            if table[slot] == EMPTY {
+                table[slot] = position;
+                return true;
+            }
+            slot = (slot + 1) % len;
+        }
+    };
+    for j in bits - n as u64..bits {
+        let t = rng.gen_range_u64(0..j + 1);
+        // j is above every position chosen so far, so it is always new.
+        let bit = if insert(t) {
+            t
+        } else {
+            insert(j);
+            j
+        };
+        data[(bit / 8) as usize] ^= 1 << (bit % 8);
+    }
+}
+
+// Overwrites n_regions regions of data with random bytes from fill_bytes.
+// Each region starts at a uniform offset and has a length drawn from
+// len_dist, cut short at the end of data; regions may overlap. Does nothing
+// to empty data. Panics if a Uniform range has min greater than max.
+pub fn corrupt_regions(
+    rng: &mut MarsagliaUniRng,
+    data: &mut [u8],
+    n_regions: usize,
+    len_dist: RegionLength,
+) {
+    if let RegionLength::Uniform { min, max } = len_dist {
+        assert!(
+            min <= max,
+            "corrupt_regions: min = {}, max = {} -- out of range",
+            min,
+            max
+        );
+    }
+    if data.is_empty() {
+        return;
+    }
+    for _ in 0..n_regions {
+        let start = rng.gen_range_u64(0..data.len() as u64) as usize;
+        let len = match len_dist {
+            RegionLength::Fixed(len) => len,
+            RegionLength::Uniform { min, max } => {
+                rng.gen_range_inclusive_u64(min as u64..=max as u64) as usize
+            }
+        };
+        let end = start.saturating_add(len).min(data.len());
+        rng.fill_bytes(&mut data[start..end]);
+    }
+}
@@ -0,0 +1,326 @@
+// Synthetic datasets for machine-learning tests, after scikit-learn's generators.
+//
+// Features are stored row-major in a flat Vec<f32>, n_features values per sample.
+
+use std::f32::consts::PI;
+
+use crate::distributions::Normal;
+use crate::MarsagliaUniRng;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dataset {
+    pub n_features: usize,
+    pub x: Vec<f32>,   // Features, row-major.
+    pub y: Vec<usize>, // One class label per sample.
+}
+
+impl Dataset {
+    pub fn len(&self) -> usize {
+        self.y.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.y.is_empty()
+    }
+
+    // Features of sample i.
+    pub fn row(&self, i: usize) -> &[f32] {
+        &self.x[i * self.n_features..(i + 1) * self.n_features]
+    }
+}
+
+// Isotropic Gaussian blobs, sample i belonging to centre i % centers.len().
+// Panics if centers is empty or std is negative or not finite.
+pub fn make_blobs<const D: usize>(
+    rng: &mut MarsagliaUniRng,
+    n: usize,
+    centers: &[[f32; D]],
+    std: f32,
+) -> Dataset {
+    assert!(!centers.is_empty(), "make_blobs: need at least one centre");
+    let std = noise_scale("make_blobs", std);
+    let mut x = vec![0.0; n * D];
+    Normal::new(0.0, 1.0).fill(rng, &mut x);
+    let y: Vec<usize> = (0..n).map(|i| i % centers.len()).collect();
+    for (row, label) in x.chunks_exact_mut(D.max(1)).zip(&y) {
+        for (v, c) in row.iter_mut().zip(&centers[*label]) {
+            *v = std * *v + c;
+        }
+    }
+    Dataset {
+        n_features: D,
+        x,
+        y,
+    }
+}
+
+// Two interleaving half circles: n / 2 on the upper moon (label 0), the rest on
+// the lower (label 1), with Gaussian noise of standard deviation noise added.
+// Panics if noise is negative or not finite.
+pub fn make_moons(rng: &mut MarsagliaUniRng, n: usize, noise: f32) -> Dataset {
+    let outer = n / 2;
+    let points = (0..n).map(|i| {
+        if i < outer {
+            let t = PI * spaced(i, outer);
+            ([t.cos(), t.sin()], 0)
+        } else {
+            let t = PI * spaced(i - outer, n - outer);
+            ([1.0 - t.cos(), 0.5 - t.sin()], 1)
+        }
+    });
+    with_noise("make_moons", rng, points, n, noise)
+}
+
+// A large circle (label 0) around a smaller one of radius factor (label 1),
+// n / 2 points on the outer, with Gaussian noise of standard deviation noise.
+// Panics if factor is outside [0, 1) or noise is negative or not finite.
+pub fn make_circles(rng: &mut MarsagliaUniRng, n: usize, noise: f32, factor: f32) -> Dataset {
+    assert!(
+        (0.0..1.0).contains(&factor),
+        "make_circles: factor = {} -- out of range",
+        factor
+    );
+    let outer = n / 2;
+    let points = (0..n).map(|i| {
+        let (k, m, r, label) = if i < outer {
+            (i, outer, 1.0, 0)
+        } else {
+            (i - outer, n - outer, factor, 1)
+        };
+        // Points evenly round the circle, not repeating the start.
+        let t = 2.0 * PI * k as f32 / m as f32;
+        ([r * t.cos(), r * t.sin()], label)
+    });
+    with_noise("make_circles", rng, points, n, noise)
+}
+
+// k of m points evenly spaced over [0, 1], ends included.
+fn spaced(k: usize, m: usize) -> f32 {
+    if m > 1 {
+        k as f32 / (m - 1) as f32
+    } else {
+        0.0
+    }
+}
+
+// Checks a noise standard deviation. Normal needs a positive one, so noise is
+// drawn as standard normals times std, which lets std be 0 for noiseless data.
+fn noise_scale(name: &str, std: f32) -> f32 {
+    assert!(
+        std.is_finite() && std >= 0.0,
+        "{}: noise = {} -- out of range",
+        name,
+        std
+    );
+    std
+}
+
+fn with_noise(
+    name: &str,
+    rng: &mut MarsagliaUniRng,
+    points: impl Iterator<Item = ([f32; 2], usize)>,
+    n: usize,
+    noise: f32,
+) -> Dataset {
+    let noise = noise_scale(name, noise);
+    let normal = Normal::new(0.0, 1.0);
+    let mut x = Vec::with_capacity(2 * n);
+    let mut y = Vec::with_capacity(n);
+    let mut jitter = [0.0; 2];
+    for (point, label) in points {
+        normal.fill(rng, &mut jitter);
+        x.extend([point[0] + noise * jitter[0], point[1] + noise * jitter[1]]);
+        y.push(label);
+    }
+    Dataset {
+        n_features: 2,
+        x,
+        y,
+    }
+}
+
+// A regression problem with its true coefficients.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegressionData {
+    pub n_features: usize,
+    pub x: Vec<f32>,    // Features, row-major.
+    pub y: Vec<f32>,    // One target per sample.
+    pub coef: Vec<f32>, // y = x . coef + noise; zero for uninformative features.
+}
+
+// Standard normal features with a linear target. The first informative
+// features get coefficients uniform in [0, 100), the rest 0, and the target
+// has Gaussian noise of standard deviation noise, which sets the signal-to-noise ratio.
+// Panics if informative > features or noise is negative or not finite.
+pub fn make_regression(
+    rng: &mut MarsagliaUniRng,
+    n: usize,
+    features: usize,
+    informative: usize,
+    noise: f32,
+) -> RegressionData {
+    assert!(
+        informative <= features,
+        "make_regression: informative = {} -- more than {} features",
+        informative,
+        features
+    );
+    let noise = noise_scale("make_regression", noise);
+    let normal = Normal::new(0.0, 1.0);
+    let mut x = vec![0.0; n * features];
+    normal.fill(rng, &mut x);
+    let coef: Vec<f32> = (0..features)
+        .map(|j| {
+            if j < informative {
+                100.0 * rng.uni()
+            } else {
+                0.0
+            }
+        })
+        .collect();
+    let y = (0..n)
+        .map(|i| {
+            let row = &x[i * features..(i + 1) * features];
+            row.iter().zip(&coef).map(|(a, b)| a * b).sum::<f32>() + noise * normal.sample(rng)
+        })
+        .collect();
+    RegressionData {
+        n_features: features,
+        x,
+        y,
+        coef,
+    }
+}
+
+// A classification problem with classes balanced as sample i in class i % classes.
+// Each class is centred on its own vertex of a hypercube with side 2 * class_sep
+// over the first informative features, where points scatter with unit normal
+// noise; the remaining features are pure noise. Larger class_sep separates the
+// classes further. Panics if classes is 0, informative > features, the cube
+// has fewer than classes vertices, informative > 63, or class_sep is not finite.
+pub fn make_classification(
+    rng: &mut MarsagliaUniRng,
+    n: usize,
+    features: usize,
+    informative: usize,
+    classes: usize,
+    class_sep: f32,
+) -> Dataset {
+    assert!(
+        classes > 0 && informative <= features.min(63) && classes as u64 <= 1 << informative,
+        "make_classification: features = {}, informative = {}, classes = {} -- out of range",
+        features,
+        informative,
+        classes
+    );
+    assert!(
+        class_sep.is_finite(),
+        "make_classification: class_sep = {} -- out of range",
+        class_sep
+    );
+    // Distinct vertices, as bit patterns over the informative features.
+    let mut vertices: Vec<u64> = Vec::with_capacity(classes);
+    while vertices.len() < classes {
+        let vertex = (0..informative).fold(0u64, |v, j| v | ((rng.uni() < 0.5) as u64) << j);
+        if !vertices.contains(&vertex) {
+            vertices.push(vertex);
+        }
+    }
+    let mut x = vec![0.0; n * features];
+    Normal::new(0.0, 1.0).fill(rng, &mut x);
+    let y: Vec<usize> = (0..n).map(|i| i % classes).collect();
+    if features > 0 {
+        for (row, label) in x.chunks_exact_mut(features).zip(&y) {
+            for (j, v) in row[..informative].iter_mut().enumerate() {
+                let bit = vertices[*label] >> j & 1;
+                *v += if bit == 1 { class_sep } else { -class_sep };
+            }
+        }
+    }
+    Dataset {
+        n_features: features,
+        x,
+        y,
+    }
+}
+
+// Replaces each label, with probability rate, by a different class chosen
+// uniformly from 0..classes. Returns the indices flipped, in order.
+// Panics if rate is outside [0, 1], or a label is not below classes when classes > 1.
+pub fn flip_labels(
+    rng: &mut MarsagliaUniRng,
+    labels: &mut [usize],
+    classes: usize,
+    rate: f32,
+) -> Vec<usize> {
+    assert!(
+        (0.0..=1.0).contains(&rate),
+        "flip_labels: rate = {} -- out of range",
+        rate
+    );
+    if classes < 2 {
+        return Vec::new();
+    }
+    let mut flipped = Vec::new();
+    for (i, label) in labels.iter_mut().enumerate() {
+        assert!(
+            *label < classes,
+            "flip_labels: label {} -- not below {} classes",
+            label,
+            classes
+        );
+        if rng.uni() < rate {
+            // Draw from the other classes by skipping over the current one.
+            let other = rng.gen_range_u64(0..classes as u64 - 1) as usize;
+            *label = if other >= *label { other + 1 } else { other };
+            flipped.push(i);
+        }
+    }
+    flipped
+}
+
+// Picks a subset whose class sizes are in proportion to ratios, indexed by
+// label, keeping as many samples as the scarcest class allows. Returns the
+// kept indices in ascending order, chosen uniformly within each class.
+// Panics if a ratio is negative or not finite, or a label has no ratio.
+pub fn subsample_to_imbalance(
+    rng: &mut MarsagliaUniRng,
+    labels: &[usize],
+    ratios: &[f32],
+) -> Vec<usize> {
+    assert!(
+        ratios.iter().all(|r| r.is_finite() && *r >= 0.0),
+        "subsample_to_imbalance: ratios = {:?} -- out of range",
+        ratios
+    );
+    let mut by_class = vec![Vec::new(); ratios.len()];
+    for (i, label) in labels.iter().enumerate() {
+        assert!(
+            *label < ratios.len(),
+            "subsample_to_imbalance: label {} -- has no ratio",
+            label
+        );
+        by_class[*label].push(i);
+    }
+    let scale = by_class
+        .iter()
+        .zip(ratios)
+        .filter(|(_, r)| **r > 0.0)
+        .map(|(members, r)| members.len() as f64 / *r as f64)
+        .fold(f64::INFINITY, f64::min);
+    if !scale.is_finite() {
+        return Vec::new();
+    }
+    let mut kept = Vec::new();
+    for (mut members, r) in by_class.into_iter().zip(ratios) {
+        let take = ((scale * *r as f64) as usize).min(members.len());
+        // Partial Fisher-Yates: the first take members become a uniform sample.
+        for k in 0..take {
+            let j = rng.gen_range_u64(k as u64..members.len() as u64) as usize;
+            members.swap(k, j);
+        }
+        kept.extend_from_slice(&members[..take]);
+    }
+    kept.sort_unstable();
+    kept
+}
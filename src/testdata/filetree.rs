@@ -0,0 +1,187 @@
+// Reproducible directory trees for testing backup, sync and indexing tools.
+//
+// random_file_tree only plans the tree; create_file_tree writes a plan to disk,
+// filling each file with bytes from the generator.
+
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::{MarsagliaUniRng, RngReader};
+
+const NAME_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789_-";
+
+// How file sizes are drawn, in bytes.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SizeDistribution {
+    Fixed(u64),
+    // Uniform over min..=max.
+    Uniform { min: u64, max: u64 },
+    // Uniform in log scale over min..=max (min >= 1), giving many small and a few large files.
+    LogUniform { min: u64, max: u64 },
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct FileTreeSpec {
+    pub max_depth: usize,              // Levels of subdirectories below the root.
+    pub dirs_per_dir: (usize, usize),  // Inclusive range of subdirectories per directory.
+    pub files_per_dir: (usize, usize), // Inclusive range of files per directory.
+    pub name_len: (usize, usize),      // Inclusive range of name lengths, in characters.
+    pub file_size: SizeDistribution,
+}
+
+impl Default for FileTreeSpec {
+    fn default() -> Self {
+        Self {
+            max_depth: 3,
+            dirs_per_dir: (0, 3),
+            files_per_dir: (0, 5),
+            name_len: (4, 12),
+            file_size: SizeDistribution::LogUniform {
+                min: 1,
+                max: 1 << 20,
+            },
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EntryKind {
+    Dir,
+    File { size: u64 },
+}
+
+// One planned entry, with its path relative to the tree's root.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlannedEntry {
+    pub path: PathBuf,
+    pub kind: EntryKind,
+}
+
+// Plans a tree, listing every directory before its contents. Panics if a
+// range in spec, including a file size range, has its low end above its high
+// end, name_len allows 0 or a LogUniform size range starts at 0.
+pub fn random_file_tree(rng: &mut MarsagliaUniRng, spec: &FileTreeSpec) -> Vec<PlannedEntry> {
+    for (name, (lo, hi)) in [
+        ("dirs_per_dir", spec.dirs_per_dir),
+        ("files_per_dir", spec.files_per_dir),
+        ("name_len", spec.name_len),
+    ] {
+        assert!(
+            lo <= hi,
+            "random_file_tree: {} = ({}, {}) is empty",
+            name,
+            lo,
+            hi
+        );
+    }
+    assert!(
+        spec.name_len.0 > 0,
+        "random_file_tree: names need at least one character"
+    );
+    let (lowest, min, max) = match spec.file_size {
+        SizeDistribution::Fixed(_) => (0, 0, 0),
+        SizeDistribution::Uniform { min, max } => (0, min, max),
+        SizeDistribution::LogUniform { min, max } => (1, min, max),
+    };
+    assert!(
+        lowest <= min && min <= max,
+        "random_file_tree: file_size = {:?} -- out of range",
+        spec.file_size
+    );
+    let mut plan = Vec::new();
+    plan_dir(rng, spec, PathBuf::new(), 0, &mut plan);
+    plan
+}
+
+fn plan_dir(
+    rng: &mut MarsagliaUniRng,
+    spec: &FileTreeSpec,
+    dir: PathBuf,
+    depth: usize,
+    plan: &mut Vec<PlannedEntry>,
+) {
+    let mut names = Vec::new();
+    let files = in_range(rng, spec.files_per_dir);
+    for _ in 0..files {
+        let name = unique_name(rng, spec, &mut names) + ".dat";
+        let size = file_size(rng, spec.file_size);
+        plan.push(PlannedEntry {
+            path: dir.join(name),
+            kind: EntryKind::File { size },
+        });
+    }
+    if depth < spec.max_depth {
+        let dirs = in_range(rng, spec.dirs_per_dir);
+        for _ in 0..dirs {
+            let path = dir.join(unique_name(rng, spec, &mut names));
+            plan.push(PlannedEntry {
+                path: path.clone(),
+                kind: EntryKind::Dir,
+            });
+            plan_dir(rng, spec, path, depth + 1, plan);
+        }
+    }
+}
+
+// Draws a name not yet used in the directory. Short names can run out, so a
+// clash is resolved by appending a counter.
+fn unique_name(rng: &mut MarsagliaUniRng, spec: &FileTreeSpec, used: &mut Vec<String>) -> String {
+    let len = in_range(rng, spec.name_len);
+    let mut name: String = (0..len)
+        .map(|_| NAME_CHARS[rng.uni_below(NAME_CHARS.len() as u32) as usize] as char)
+        .collect();
+    let base = name.clone();
+    let mut counter = 1;
+    while used.contains(&name) {
+        name = format!("{}{}", base, counter);
+        counter += 1;
+    }
+    used.push(name.clone());
+    name
+}
+
+fn in_range(rng: &mut MarsagliaUniRng, (lo, hi): (usize, usize)) -> usize {
+    rng.gen_range_inclusive_u64(lo as u64..=hi as u64) as usize
+}
+
+fn file_size(rng: &mut MarsagliaUniRng, dist: SizeDistribution) -> u64 {
+    match dist {
+        SizeDistribution::Fixed(size) => size,
+        SizeDistribution::Uniform { min, max } => rng.gen_range_inclusive_u64(min..=max),
+        SizeDistribution::LogUniform { min, max } => {
+            let (lo, hi) = (min as f64, max as f64);
+            let size = (lo.ln() + (hi.ln() - lo.ln()) * rng.uni() as f64).exp();
+            (size.round() as u64).clamp(min, max)
+        }
+    }
+}
+
+// Writes a plan under root, which must already exist. File contents come from rng.
+pub fn create_file_tree(
+    rng: &mut MarsagliaUniRng,
+    plan: &[PlannedEntry],
+    root: &Path,
+) -> io::Result<()> {
+    let mut bytes = RngReader::new(rng.clone());
+    let mut buf = vec![0u8; 64 * 1024];
+    for entry in plan {
+        let path = root.join(&entry.path);
+        match entry.kind {
+            EntryKind::Dir => fs::create_dir(&path)?,
+            EntryKind::File { size } => {
+                let mut out = BufWriter::new(File::create(&path)?);
+                let mut left = size;
+                while left > 0 {
+                    let n = left.min(buf.len() as u64) as usize;
+                    bytes.read_exact(&mut buf[..n])?;
+                    out.write_all(&buf[..n])?;
+                    left -= n as u64;
+                }
+                out.flush()?;
+            }
+        }
+    }
+    *rng = bytes.into_inner();
+    Ok(())
+}
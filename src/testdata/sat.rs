@@ -0,0 +1,65 @@
+// Random k-SAT instances for solver benchmarking.
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, string::ToString, vec, vec::Vec};
+
+use crate::MarsagliaUniRng;
+
+// Returns n_clauses clauses of k literals in DIMACS form: variable v is v or -v,
+// with variables numbered from 1. Each clause uses k distinct variables, chosen
+// uniformly, each negated with probability 1/2, so no clause repeats a literal
+// or contains a literal together with its negation.
+// Panics unless 1 <= k <= n_vars <= i32::MAX, the largest variable a literal can name.
+#[cfg(feature = "alloc")]
+pub fn random_ksat(
+    rng: &mut MarsagliaUniRng,
+    n_vars: usize,
+    n_clauses: usize,
+    k: usize,
+) -> Vec<Vec<i32>> {
+    check_clause(n_vars, k);
+    (0..n_clauses)
+        .map(|_| {
+            let mut clause = vec![0; k];
+            random_clause(rng, n_vars, &mut clause);
+            clause
+        })
+        .collect()
+}
+
+// Fills clause with one random_ksat clause of clause.len() literals, without allocating.
+// Panics unless 1 <= clause.len() <= n_vars <= i32::MAX.
+pub fn random_clause(rng: &mut MarsagliaUniRng, n_vars: usize, clause: &mut [i32]) {
+    check_clause(n_vars, clause.len());
+    let mut filled = 0;
+    while filled < clause.len() {
+        let var = rng.gen_range_u64(0..n_vars as u64) as i32 + 1;
+        if clause[..filled].iter().all(|lit| lit.abs() != var) {
+            clause[filled] = if rng.uni() < 0.5 { var } else { -var };
+            filled += 1;
+        }
+    }
+}
+
+fn check_clause(n_vars: usize, k: usize) {
+    assert!(
+        k >= 1 && k <= n_vars && n_vars <= i32::MAX as usize,
+        "random_ksat: need 1 <= k <= n_vars <= i32::MAX, got k = {}, n_vars = {}",
+        k,
+        n_vars
+    );
+}
+
+// Formats clauses as a DIMACS CNF file.
+#[cfg(feature = "alloc")]
+pub fn to_dimacs(n_vars: usize, clauses: &[Vec<i32>]) -> String {
+    let mut out = format!("p cnf {} {}\n", n_vars, clauses.len());
+    for clause in clauses {
+        for lit in clause {
+            out.push_str(&lit.to_string());
+            out.push(' ');
+        }
+        out.push_str("0\n");
+    }
+    out
+}
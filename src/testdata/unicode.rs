@@ -0,0 +1,106 @@
+// Reproducible hostile strings for testing parsers, renderers and terminals.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::MarsagliaUniRng;
+
+// A group of characters to draw from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UnicodeClass {
+    // Printable ASCII, U+0020 to U+007E.
+    Ascii,
+    // C0 control characters and DEL.
+    Control,
+    // Combining diacritical marks, U+0300 to U+036F, which attach to the previous character.
+    CombiningMark,
+    // Valid characters either side of the surrogate gap and at the ends of the
+    // planes: U+D7FF, U+E000, U+FFFD, U+FFFF, U+10000, U+10FFFF and friends.
+    SurrogateAdjacent,
+    // Bidirectional controls: LRM, RLM, ALM and the embedding, override and isolate marks.
+    Bidi,
+    // Emoji, skin-tone modifiers, the zero-width joiner and variation selector 16.
+    Emoji,
+    // Zero-width and invisible characters: ZWSP, ZWNJ, word joiner, BOM.
+    ZeroWidth,
+    // Any Unicode scalar value, i.e. any code point except surrogates.
+    Any,
+}
+
+const SURROGATE_ADJACENT: [u32; 10] = [
+    0xD7FE, 0xD7FF, 0xE000, 0xE001, 0xFFFD, 0xFFFE, 0xFFFF, 0x10000, 0x10FFFE, 0x10FFFF,
+];
+const BIDI: [u32; 12] = [
+    0x200E, 0x200F, 0x061C, 0x202A, 0x202B, 0x202C, 0x202D, 0x202E, 0x2066, 0x2067, 0x2068, 0x2069,
+];
+const EMOJI_EXTRAS: [u32; 7] = [0x200D, 0xFE0F, 0x1F3FB, 0x1F3FC, 0x1F3FD, 0x1F3FE, 0x1F3FF];
+const ZERO_WIDTH: [u32; 5] = [0x200B, 0x200C, 0x2060, 0xFEFF, 0x180E];
+
+// Returns a string of len characters, each from a class chosen uniformly from classes.
+// Panics if classes is empty.
+#[cfg(feature = "alloc")]
+pub fn random_unicode_string(
+    rng: &mut MarsagliaUniRng,
+    len: usize,
+    classes: &[UnicodeClass],
+) -> String {
+    check_classes(classes);
+    (0..len).map(|_| random_class_char(rng, classes)).collect()
+}
+
+// Fills out with the characters random_unicode_string would return, without allocating.
+// Panics if classes is empty.
+pub fn fill_random_unicode(rng: &mut MarsagliaUniRng, out: &mut [char], classes: &[UnicodeClass]) {
+    check_classes(classes);
+    for c in out.iter_mut() {
+        *c = random_class_char(rng, classes);
+    }
+}
+
+fn check_classes(classes: &[UnicodeClass]) {
+    assert!(
+        !classes.is_empty(),
+        "random_unicode_string: no classes given"
+    );
+}
+
+fn random_class_char(rng: &mut MarsagliaUniRng, classes: &[UnicodeClass]) -> char {
+    let class = classes[rng.uni_below(classes.len() as u32) as usize];
+    random_char(rng, class)
+}
+
+fn random_char(rng: &mut MarsagliaUniRng, class: UnicodeClass) -> char {
+    let code = match class {
+        UnicodeClass::Ascii => 0x20 + rng.uni_below(0x5F),
+        UnicodeClass::Control => match rng.uni_below(33) {
+            32 => 0x7F,
+            c => c,
+        },
+        UnicodeClass::CombiningMark => 0x300 + rng.uni_below(0x70),
+        UnicodeClass::SurrogateAdjacent => pick(rng, &SURROGATE_ADJACENT),
+        UnicodeClass::Bidi => pick(rng, &BIDI),
+        UnicodeClass::Emoji => {
+            // Mostly pictographs from U+1F300 to U+1FAFF, sometimes a modifier or joiner.
+            if rng.uni() < 0.75 {
+                0x1F300 + rng.uni_below(0x800)
+            } else {
+                pick(rng, &EMOJI_EXTRAS)
+            }
+        }
+        UnicodeClass::ZeroWidth => pick(rng, &ZERO_WIDTH),
+        UnicodeClass::Any => {
+            // There are 0x110000 - 0x800 scalar values; step over the surrogate gap.
+            let c = rng.uni_below(0x110000 - 0x800);
+            if c >= 0xD800 {
+                c + 0x800
+            } else {
+                c
+            }
+        }
+    };
+    char::from_u32(code).expect("every class holds only scalar values")
+}
+
+fn pick(rng: &mut MarsagliaUniRng, table: &[u32]) -> u32 {
+    table[rng.uni_below(table.len() as u32) as usize]
+}
@@ -0,0 +1,3 @@
+// Seedable synthetic workloads for load and capacity testing.
+
+pub mod http;
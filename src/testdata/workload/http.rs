@@ -0,0 +1,127 @@
+// HTTP-like request streams: Zipf-popular paths, log-normal sizes and Poisson arrivals.
+
+use crate::distributions::{Exponential, LogNormal, Zipf};
+use crate::MarsagliaUniRng;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Method {
+    Get,
+    Head,
+    Post,
+    Put,
+    Delete,
+}
+
+impl Method {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Method::Get => "GET",
+            Method::Head => "HEAD",
+            Method::Post => "POST",
+            Method::Put => "PUT",
+            Method::Delete => "DELETE",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HttpWorkloadSpec {
+    pub methods: Vec<(Method, f32)>, // Methods with relative weights.
+    pub paths: usize,                // Number of distinct paths, /item/1 to /item/{paths}.
+    pub zipf_exponent: f64,          // Popularity skew; path k has weight 1 / k^s.
+    pub size_mu: f32,                // Mean of ln(response bytes).
+    pub size_sigma: f32,             // Standard deviation of ln(response bytes).
+    pub rate: f32,                   // Mean requests per second.
+}
+
+impl Default for HttpWorkloadSpec {
+    fn default() -> Self {
+        Self {
+            methods: vec![
+                (Method::Get, 80.0),
+                (Method::Head, 5.0),
+                (Method::Post, 10.0),
+                (Method::Put, 3.0),
+                (Method::Delete, 2.0),
+            ],
+            paths: 1000,
+            zipf_exponent: 1.0,
+            size_mu: 8.0,
+            size_sigma: 1.5,
+            rate: 100.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct HttpRequest {
+    pub method: Method,
+    pub path: String,
+    pub size: u64,          // Response size in bytes.
+    pub inter_arrival: f64, // Seconds since the previous request.
+}
+
+// An endless iterator of requests drawn from a spec.
+#[derive(Clone, Debug)]
+pub struct HttpWorkload {
+    rng: MarsagliaUniRng,
+    methods: Vec<(Method, f32)>,
+    method_total: f32,
+    paths: Zipf,
+    sizes: LogNormal,
+    arrivals: Exponential,
+}
+
+impl HttpWorkload {
+    // Panics if the spec has no methods, a negative or all-zero weight, or
+    // parameters its distributions reject.
+    pub fn new(rng: MarsagliaUniRng, spec: &HttpWorkloadSpec) -> Self {
+        let method_total: f32 = spec.methods.iter().map(|(_, w)| *w).sum();
+        assert!(
+            method_total > 0.0 && spec.methods.iter().all(|(_, w)| *w >= 0.0),
+            "HttpWorkload::new: method weights must be non-negative and not all zero"
+        );
+        Self {
+            rng,
+            methods: spec.methods.clone(),
+            method_total,
+            paths: Zipf::new(spec.paths, spec.zipf_exponent),
+            sizes: LogNormal::new(spec.size_mu, spec.size_sigma),
+            arrivals: Exponential::new(spec.rate),
+        }
+    }
+
+    fn method(&mut self) -> Method {
+        let mut target = self.rng.uni() * self.method_total;
+        for (method, weight) in &self.methods {
+            if target < *weight {
+                return *method;
+            }
+            target -= weight;
+        }
+        // Rounding can leave target just above the last weight.
+        self.methods
+            .iter()
+            .rev()
+            .find(|(_, w)| *w > 0.0)
+            .expect("a positive weight")
+            .0
+    }
+}
+
+impl Iterator for HttpWorkload {
+    type Item = HttpRequest;
+
+    fn next(&mut self) -> Option<HttpRequest> {
+        let method = self.method();
+        let path = format!("/item/{}", self.paths.sample(&mut self.rng));
+        let size = self.sizes.sample(&mut self.rng).round() as u64;
+        let inter_arrival = self.arrivals.sample(&mut self.rng) as f64;
+        Some(HttpRequest {
+            method,
+            path,
+            size,
+            inter_arrival,
+        })
+    }
+}
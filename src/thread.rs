@@ -0,0 +1,70 @@
+// A lazily seeded generator per thread, and free functions drawing from it.
+//
+// For scripts and examples that would rather not pass a generator around.
+// Each thread's generator is seeded on first use from the clock and a
+// per-process thread count, so threads started together still differ; the
+// seed chosen is reported by thread_rng_seed so a run can be repeated, and
+// seed_thread_rng replaces it with a fixed seed.
+
+use std::cell::RefCell;
+use std::ops::Range;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{mix64, MarsagliaUniRng};
+
+thread_local! {
+    static THREAD_RNG: RefCell<Option<(MarsagliaUniRng, i32)>> = const { RefCell::new(None) };
+}
+
+static THREADS_SEEDED: AtomicU64 = AtomicU64::new(0);
+
+fn fresh_seed() -> i32 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    let clock = now.as_secs() ^ ((now.subsec_nanos() as u64) << 32);
+    let count = THREADS_SEEDED.fetch_add(1, Ordering::Relaxed);
+    (mix64(clock ^ mix64(count)) % 900_000_001) as i32
+}
+
+fn seeded(seed: i32) -> (MarsagliaUniRng, i32) {
+    (MarsagliaUniRng::unseeded().rinit(seed), seed)
+}
+
+// Runs f with this thread's generator and its seed, seeding it first if needed.
+fn with_state<T>(f: impl FnOnce(&mut (MarsagliaUniRng, i32)) -> T) -> T {
+    THREAD_RNG.with(|cell| {
+        f(cell
+            .borrow_mut()
+            .get_or_insert_with(|| seeded(fresh_seed())))
+    })
+}
+
+// Runs f with this thread's generator, seeding it first if needed. Panics if
+// f itself uses the thread's generator, such as by calling uni.
+pub fn with_thread_rng<T>(f: impl FnOnce(&mut MarsagliaUniRng) -> T) -> T {
+    with_state(|(rng, _)| f(rng))
+}
+
+// Reseeds this thread's generator with rinit(seed). Panics if seed is
+// outside 0..=900_000_000.
+pub fn seed_thread_rng(seed: i32) {
+    THREAD_RNG.with(|cell| *cell.borrow_mut() = Some(seeded(seed)));
+}
+
+// Returns the rinit seed of this thread's generator, seeding it first if needed.
+pub fn thread_rng_seed() -> i32 {
+    with_state(|(_, seed)| *seed)
+}
+
+// Returns the next uni output of this thread's generator.
+pub fn uni() -> f32 {
+    with_thread_rng(|rng| rng.uni())
+}
+
+// Returns a uniform integer in range from this thread's generator, as
+// gen_range_u32 does. Panics if range is empty.
+pub fn range(range: Range<u32>) -> u32 {
+    with_thread_rng(|rng| rng.gen_range_u32(range))
+}
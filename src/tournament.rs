@@ -0,0 +1,109 @@
+// Reproducible draws for tournaments: knockout brackets, round-robin
+// schedules and Swiss pairings.
+//
+// Teams are numbered 0..n. Every draw depends only on the arguments and the
+// generator state, so a tournament can be redrawn exactly from its seed.
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+
+use crate::sampling::argsort_with_random_ties;
+use crate::MarsagliaUniRng;
+
+// A fair coin from one output.
+fn coin(rng: &mut MarsagliaUniRng) -> bool {
+    rng.next_u32() & 1 == 1
+}
+
+// Draws the first round of a knockout bracket for n_teams, in bracket order:
+// the winners of matches 2m and 2m + 1 meet in the second round. The bracket
+// has the next power of two of slots, and each slot left empty is a bye
+// (None) given to a different team, so no match is between two byes. Panics
+// if n_teams is below 2.
+pub fn random_bracket(rng: &mut MarsagliaUniRng, n_teams: usize) -> Vec<(usize, Option<usize>)> {
+    assert!(
+        n_teams >= 2,
+        "random_bracket: n_teams = {} -- out of range",
+        n_teams
+    );
+    let byes = n_teams.next_power_of_two() - n_teams;
+    let mut teams: Vec<usize> = (0..n_teams).collect();
+    rng.shuffle(&mut teams);
+    let (with_byes, paired) = teams.split_at(byes);
+    let mut matches: Vec<(usize, Option<usize>)> = with_byes
+        .iter()
+        .map(|team| (*team, None))
+        .chain(paired.chunks_exact(2).map(|pair| (pair[0], Some(pair[1]))))
+        .collect();
+    rng.shuffle(&mut matches);
+    matches
+}
+
+// Draws a round-robin schedule in which every pair of the n teams meets once,
+// as a list of rounds of (home, away) matches. The circle method builds the
+// rounds; teams are then relabelled at random, the rounds put in random
+// order and each match's home side chosen by a coin flip. With n odd each
+// round leaves one team out. Panics if n is below 2.
+pub fn round_robin_schedule_shuffled(
+    rng: &mut MarsagliaUniRng,
+    n: usize,
+) -> Vec<Vec<(usize, usize)>> {
+    assert!(
+        n >= 2,
+        "round_robin_schedule_shuffled: n = {} -- out of range",
+        n
+    );
+    // With n odd, position m - 1 is a dummy and its opponent sits out.
+    let m = n + n % 2;
+    let mut labels: Vec<usize> = (0..n).collect();
+    rng.shuffle(&mut labels);
+    let mut circle: Vec<usize> = (0..m).collect();
+    let mut rounds = Vec::with_capacity(m - 1);
+    for _ in 0..m - 1 {
+        let mut round = Vec::with_capacity(m / 2);
+        for i in 0..m / 2 {
+            let (a, b) = (circle[i], circle[m - 1 - i]);
+            if a < n && b < n {
+                let (a, b) = (labels[a], labels[b]);
+                round.push(if coin(rng) { (a, b) } else { (b, a) });
+            }
+        }
+        rounds.push(round);
+        // Hold position 0 and rotate the rest one place.
+        circle[1..].rotate_right(1);
+    }
+    rng.shuffle(&mut rounds);
+    rounds
+}
+
+// Pairs players for a Swiss round. Players are ranked by score, highest
+// first, with equal scores in random order; each unpaired player in turn is
+// paired with the highest-ranked unpaired player it has not met in previous,
+// or with the next unpaired player if it has met them all. With an odd number
+// of players the lowest-ranked player left over gets the bye, returned second.
+pub fn swiss_pairings(
+    rng: &mut MarsagliaUniRng,
+    scores: &[u32],
+    previous: &[(usize, usize)],
+) -> (Vec<(usize, usize)>, Option<usize>) {
+    let met: HashSet<(usize, usize)> = previous
+        .iter()
+        .map(|(a, b)| (*a.min(b), *a.max(b)))
+        .collect();
+    let mut unpaired = argsort_with_random_ties(rng, scores, |s| Reverse(*s));
+    let bye = if unpaired.len() % 2 == 1 {
+        unpaired.pop()
+    } else {
+        None
+    };
+    let mut pairs = Vec::with_capacity(unpaired.len() / 2);
+    while unpaired.len() >= 2 {
+        let top = unpaired.remove(0);
+        let choice = unpaired
+            .iter()
+            .position(|p| !met.contains(&(top.min(*p), top.max(*p))))
+            .unwrap_or(0);
+        pairs.push((top, unpaired.remove(choice)));
+    }
+    (pairs, bye)
+}
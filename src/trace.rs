@@ -0,0 +1,47 @@
+// Step-by-step trace of the subtract-with-borrow recurrence, for teaching.
+//
+// `uni_traced` performs exactly the same update as `uni` and records every
+// intermediate value, so the traced stream matches the untraced one.
+
+use crate::MarsagliaUniRng;
+
+// The intermediate values behind one output of the generator.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StepTrace {
+    pub ui: usize,           // Table position updated by this step.
+    pub uj: usize,           // Table position subtracted from it.
+    pub u_i: f32,            // Value at ui before the update.
+    pub u_j: f32,            // Value at uj.
+    pub lagged: f32,         // u_i - u_j, wrapped into [0, 1), now stored at ui.
+    pub lag_borrow: bool,    // Whether u_i - u_j was negative and had 1 added.
+    pub c_before: f32,       // Correction before this step.
+    pub c_after: f32,        // Correction after subtracting cd (mod cm).
+    pub c_wrapped: bool,     // Whether the correction wrapped by adding cm.
+    pub output_borrow: bool, // Whether lagged - c_after was negative and had 1 added.
+}
+
+impl MarsagliaUniRng {
+    // Generates the next value, as `uni` does, along with how it was formed.
+    pub fn uni_traced(&mut self) -> (f32, StepTrace) {
+        let (ui, uj) = (self.uni_ui, self.uni_uj);
+        let (u_i, u_j) = (self.uni_u[ui], self.uni_u[uj]);
+        let c_before = self.uni_c;
+        let lagged_raw = u_i - u_j;
+        let lag_borrow = lagged_raw < 0.0;
+        let value = self.uni();
+        let lagged = self.uni_u[ui];
+        let trace = StepTrace {
+            ui,
+            uj,
+            u_i,
+            u_j,
+            lagged,
+            lag_borrow,
+            c_before,
+            c_after: self.uni_c,
+            c_wrapped: c_before - self.uni_cd < 0.0,
+            output_borrow: lagged - self.uni_c < 0.0,
+        };
+        (value, trace)
+    }
+}
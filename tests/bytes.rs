@@ -0,0 +1,52 @@
+// Byte output from fill_bytes and its agreement with RngReader.
+
+use unirand::{Endian, MarsagliaUniRng};
+
+#[test]
+fn bytes_are_little_endian_words() {
+    let mut words = MarsagliaUniRng::from_seed(170);
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let mut buf = [0u8; 30];
+    rng.fill_bytes(&mut buf);
+    for chunk in buf.chunks(3) {
+        let (w, _) = words.fraction();
+        assert_eq!(chunk, &w.to_le_bytes()[..3]);
+    }
+}
+
+#[test]
+fn big_endian_reverses_each_word() {
+    let mut little = [0u8; 12];
+    let mut big = [0u8; 12];
+    MarsagliaUniRng::from_seed(170).fill_bytes(&mut little);
+    MarsagliaUniRng::from_seed(170).fill_bytes_endian(&mut big, Endian::Big);
+    for (l, b) in little.chunks(3).zip(big.chunks(3)) {
+        assert_eq!([l[2], l[1], l[0]], [b[0], b[1], b[2]]);
+    }
+}
+
+#[test]
+fn partial_chunk_uses_one_more_draw() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let mut short = [0u8; 7];
+    rng.fill_bytes(&mut short);
+    let mut long = [0u8; 9];
+    MarsagliaUniRng::from_seed(170).fill_bytes(&mut long);
+    assert_eq!(short, long[..7]);
+    assert_eq!(rng.draws(), 3);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn matches_rng_reader() {
+    use std::io::Read;
+    for endian in [Endian::Little, Endian::Big] {
+        let mut from_reader = [0u8; 300];
+        unirand::RngReader::with_endian(MarsagliaUniRng::from_seed(42), endian)
+            .read_exact(&mut from_reader)
+            .unwrap();
+        let mut filled = [0u8; 300];
+        MarsagliaUniRng::from_seed(42).fill_bytes_endian(&mut filled, endian);
+        assert_eq!(from_reader, filled);
+    }
+}
@@ -0,0 +1,95 @@
+// Frequencies of the Gumbel-max and exponential-race categorical samplers.
+
+use unirand::distributions::Gumbel;
+use unirand::sampling::{exponential_race, gumbel_max_pick};
+use unirand::stats::chi_square_p_value;
+use unirand::MarsagliaUniRng;
+
+const DRAWS: usize = 60_000;
+
+// Chi-square p-value of counts against probabilities proportional to weights.
+fn fit(counts: &[usize], weights: &[f64]) -> f64 {
+    let total: f64 = weights.iter().sum();
+    let mut statistic = 0.0;
+    let mut cells = 0;
+    for (count, weight) in counts.iter().zip(weights) {
+        if *weight == 0.0 {
+            assert_eq!(*count, 0, "a zero-weight index was picked");
+            continue;
+        }
+        let expected = DRAWS as f64 * weight / total;
+        statistic += (*count as f64 - expected).powi(2) / expected;
+        cells += 1;
+    }
+    chi_square_p_value(statistic, cells - 1)
+}
+
+#[test]
+fn gumbel_max_pick_follows_softmax() {
+    let weights = [1.0, 2.0, 3.0, 0.0, 0.5];
+    let log_weights: Vec<f64> = weights.iter().map(|w: &f64| w.ln()).collect();
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let mut counts = [0; 5];
+    for _ in 0..DRAWS {
+        counts[gumbel_max_pick(&mut rng, &log_weights)] += 1;
+    }
+    let p = fit(&counts, &weights);
+    assert!(p > 1e-4, "counts {:?} give p = {}", counts, p);
+}
+
+#[test]
+fn gumbel_max_pick_ignores_shift() {
+    let mut a = MarsagliaUniRng::from_seed(42);
+    let mut b = MarsagliaUniRng::from_seed(42);
+    for _ in 0..1_000 {
+        assert_eq!(
+            gumbel_max_pick(&mut a, &[0.0, 1.0, 2.0]),
+            gumbel_max_pick(&mut b, &[-1000.0, -999.0, -998.0])
+        );
+    }
+}
+
+#[test]
+#[should_panic]
+fn gumbel_max_pick_rejects_all_zero_weights() {
+    gumbel_max_pick(
+        &mut MarsagliaUniRng::from_seed(170),
+        &[f64::NEG_INFINITY, f64::NEG_INFINITY],
+    );
+}
+
+#[test]
+fn exponential_race_winner_and_time() {
+    let rates = [1.0, 2.0, 0.0, 3.0];
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let mut counts = [0; 4];
+    let mut time = 0.0;
+    for _ in 0..DRAWS {
+        let (winner, t) = exponential_race(&mut rng, &rates);
+        counts[winner] += 1;
+        time += t;
+    }
+    let p = fit(&counts, &rates);
+    assert!(p > 1e-4, "counts {:?} give p = {}", counts, p);
+    let mean = time / DRAWS as f64;
+    assert!((mean - 1.0 / 6.0).abs() < 0.005, "mean time {}", mean);
+}
+
+#[test]
+fn gumbel_mean_and_finite() {
+    let gumbel = Gumbel::new(1.0, 2.0);
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let mut sum = 0.0;
+    for _ in 0..DRAWS {
+        let x = gumbel.sample(&mut rng);
+        assert!(x.is_finite());
+        sum += x as f64;
+    }
+    // The mean is mu + beta * the Euler-Mascheroni constant.
+    let mean = sum / DRAWS as f64;
+    assert!(
+        (mean - (1.0 + 2.0 * 0.577_215_7)).abs() < 0.03,
+        "mean {}",
+        mean
+    );
+}
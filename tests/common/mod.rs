@@ -0,0 +1,108 @@
+// Shared fixtures and statistical checks for the integration tests. Each test
+// binary uses only some of them.
+//
+// assert_moments compares the sample mean, variance and skewness of a sampler
+// with their analytic values over several seeds. Each statistic's standard
+// error is estimated from its spread across equal batches of the sample, so
+// the check needs no higher moments and suits heavy-tailed samplers too. The
+// critical value is Bonferroni-adjusted for every statistic and seed tested,
+// with a Student t correction for the batch count.
+
+#![allow(dead_code)]
+
+use unirand::{Indexing, MarsagliaUniRng, Unseeded};
+
+pub const SEEDS: [i32; 4] = [170, 54217137, 123_456_789, 900_000_000];
+
+const SAMPLES: usize = 200_000;
+const BATCHES: usize = 100;
+const FAMILY_ALPHA: f64 = 1e-4;
+
+// Analytic moments of a distribution.
+#[derive(Clone, Copy, Debug)]
+pub struct Moments {
+    pub mean: f64,
+    pub variance: f64,
+    pub skewness: f64,
+}
+
+// Sample mean, variance and skewness.
+fn sample_moments(xs: &[f64]) -> [f64; 3] {
+    let n = xs.len() as f64;
+    let mean = xs.iter().sum::<f64>() / n;
+    let (mut m2, mut m3) = (0.0, 0.0);
+    for x in xs {
+        let d = x - mean;
+        m2 += d * d;
+        m3 += d * d * d;
+    }
+    let (m2, m3) = (m2 / n, m3 / n);
+    [mean, m2 * n / (n - 1.0), m3 / m2.powf(1.5)]
+}
+
+// The upper alpha quantile of the standard normal (Acklam's approximation).
+fn normal_quantile(alpha: f64) -> f64 {
+    let q = (-2.0 * alpha.ln()).sqrt();
+    let c = [
+        -7.784894002430293e-03,
+        -3.223964580411365e-01,
+        -2.400758277161838,
+        -2.549732539343734,
+        4.374664141464968,
+        2.938163982698783,
+    ];
+    let d = [
+        7.784695709041462e-03,
+        3.224671290700398e-01,
+        2.445134137142996,
+        3.754408661907416,
+    ];
+    -(((((c[0] * q + c[1]) * q + c[2]) * q + c[3]) * q + c[4]) * q + c[5])
+        / ((((d[0] * q + d[1]) * q + d[2]) * q + d[3]) * q + 1.0)
+}
+
+// The upper alpha quantile of Student's t with dof degrees of freedom (Cornish-Fisher).
+fn t_quantile(alpha: f64, dof: f64) -> f64 {
+    let z = normal_quantile(alpha);
+    let z3 = z * z * z;
+    z + (z3 + z) / (4.0 * dof) + (5.0 * z3 * z * z + 16.0 * z3 + 3.0 * z) / (96.0 * dof * dof)
+}
+
+// Checks the moments of draw(seed, n), which returns n samples from a
+// generator seeded with seed, against expected for each of SEEDS.
+pub fn assert_moments(name: &str, expected: Moments, mut draw: impl FnMut(i32, usize) -> Vec<f64>) {
+    let tests = 3 * SEEDS.len();
+    let critical = t_quantile(FAMILY_ALPHA / (2.0 * tests as f64), (BATCHES - 1) as f64);
+    let wanted = [expected.mean, expected.variance, expected.skewness];
+    let labels = ["mean", "variance", "skewness"];
+    for seed in SEEDS {
+        let xs = draw(seed, SAMPLES);
+        assert_eq!(xs.len(), SAMPLES);
+        let pooled = sample_moments(&xs);
+        let batches: Vec<[f64; 3]> = xs.chunks(SAMPLES / BATCHES).map(sample_moments).collect();
+        for k in 0..3 {
+            let b = BATCHES as f64;
+            let mean = batches.iter().map(|m| m[k]).sum::<f64>() / b;
+            let var = batches.iter().map(|m| (m[k] - mean).powi(2)).sum::<f64>() / (b - 1.0);
+            // The pooled statistic's error is that of one batch over sqrt(batches).
+            let se = (var / b).sqrt();
+            let z = (pooled[k] - wanted[k]) / se;
+            assert!(
+                z.abs() < critical,
+                "{} seed {}: {} {} vs {} (z = {:.2}, limit {:.2})",
+                name,
+                seed,
+                labels[k],
+                pooled[k],
+                wanted[k],
+                z,
+                critical
+            );
+        }
+    }
+}
+
+// A generator with the given indexing, seeded with rinit(ijkl).
+pub fn seeded(indexing: Indexing, ijkl: i32) -> MarsagliaUniRng {
+    Unseeded::with_indexing(indexing).rinit(ijkl)
+}
@@ -0,0 +1,124 @@
+#![cfg(feature = "alloc")]
+
+use unirand::testdata::corrupt::{
+    corrupt_regions, flip_random_bits, flip_random_bits_with_scratch, RegionLength,
+};
+use unirand::MarsagliaUniRng;
+
+fn flipped_bits(a: &[u8], b: &[u8]) -> u32 {
+    a.iter().zip(b).map(|(x, y)| (x ^ y).count_ones()).sum()
+}
+
+#[test]
+fn flips_exactly_n_bits_reproducibly() {
+    let original: Vec<u8> = (0..=255).collect();
+    for n in [0, 1, 7, 100, 2048] {
+        let mut data = original.clone();
+        flip_random_bits(&mut MarsagliaUniRng::from_seed(170), &mut data, n);
+        assert_eq!(flipped_bits(&original, &data), n as u32);
+        let mut again = original.clone();
+        flip_random_bits(&mut MarsagliaUniRng::from_seed(170), &mut again, n);
+        assert_eq!(data, again);
+    }
+}
+
+#[test]
+fn every_bit_position_can_be_flipped() {
+    let mut rng = MarsagliaUniRng::from_seed(9);
+    let mut hits = [0u32; 32];
+    for _ in 0..2000 {
+        let mut data = [0u8; 4];
+        flip_random_bits(&mut rng, &mut data, 1);
+        hits[u32::from_le_bytes(data).trailing_zeros() as usize] += 1;
+    }
+    assert!(hits.iter().all(|h| *h > 25), "{:?}", hits);
+}
+
+#[test]
+fn scratch_form_matches_and_ignores_extra_room() {
+    let original = [0x5au8; 64];
+    let mut data = original;
+    flip_random_bits(&mut MarsagliaUniRng::from_seed(3), &mut data, 40);
+    let mut again = original;
+    let mut scratch = [7u64; 100];
+    flip_random_bits_with_scratch(
+        &mut MarsagliaUniRng::from_seed(3),
+        &mut again,
+        40,
+        &mut scratch,
+    );
+    assert_eq!(data, again);
+    assert!(scratch[80..].iter().all(|x| *x == 7));
+}
+
+#[test]
+fn regions_stay_within_their_lengths() {
+    let original = vec![0u8; 10_000];
+    let mut data = original.clone();
+    let mut rng = MarsagliaUniRng::from_seed(54217137);
+    corrupt_regions(&mut rng, &mut data, 3, RegionLength::Fixed(16));
+    let changed: Vec<usize> = (0..data.len()).filter(|i| data[*i] != 0).collect();
+    assert!(!changed.is_empty() && changed.len() <= 48);
+
+    let mut data = original.clone();
+    corrupt_regions(
+        &mut rng,
+        &mut data,
+        1,
+        RegionLength::Uniform { min: 100, max: 200 },
+    );
+    let first = data.iter().position(|b| *b != 0).unwrap();
+    let last = data.iter().rposition(|b| *b != 0).unwrap();
+    assert!(last - first < 200);
+    assert!(last - first >= 90 || last == data.len() - 1);
+}
+
+#[test]
+fn regions_are_cut_at_the_end_and_empty_data_is_left_alone() {
+    let mut data = [0u8; 8];
+    corrupt_regions(
+        &mut MarsagliaUniRng::from_seed(1),
+        &mut data,
+        50,
+        RegionLength::Fixed(usize::MAX),
+    );
+    assert!(data.iter().any(|b| *b != 0));
+    corrupt_regions(
+        &mut MarsagliaUniRng::from_seed(1),
+        &mut [],
+        5,
+        RegionLength::Fixed(4),
+    );
+}
+
+#[test]
+fn uniform_lengths_can_span_every_usize() {
+    let mut data = [0u8; 64];
+    corrupt_regions(
+        &mut MarsagliaUniRng::from_seed(1),
+        &mut data,
+        4,
+        RegionLength::Uniform {
+            min: 0,
+            max: usize::MAX,
+        },
+    );
+    assert!(data.iter().any(|b| *b != 0));
+}
+
+#[test]
+#[should_panic(expected = "flip_random_bits: n = 17 -- out of range")]
+fn too_many_flips_panic() {
+    flip_random_bits(&mut MarsagliaUniRng::from_seed(1), &mut [0u8; 2], 17);
+}
+
+#[test]
+#[should_panic(expected = "flip_random_bits: scratch.len() = 3 -- shorter than 2n")]
+fn short_scratch_panics() {
+    flip_random_bits_with_scratch(
+        &mut MarsagliaUniRng::from_seed(1),
+        &mut [0u8; 2],
+        2,
+        &mut [0; 3],
+    );
+}
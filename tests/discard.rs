@@ -0,0 +1,56 @@
+mod common;
+
+use common::seeded;
+use unirand::Indexing;
+
+#[test]
+fn discard_matches_stepping() {
+    for indexing in [Indexing::Port, Indexing::Ranmar] {
+        for (seed, n) in [
+            (170, 0),
+            (170, 1),
+            (1802, 4097),
+            (54217137, 10_000),
+            (7, 123_457),
+        ] {
+            let mut jumped = seeded(indexing, seed);
+            jumped.uni();
+            jumped.discard(n);
+            let mut stepped = seeded(indexing, seed);
+            for _ in 0..=n {
+                stepped.uni();
+            }
+            assert_eq!(jumped, stepped, "{:?} seed {} n {}", indexing, seed, n);
+            assert_eq!(jumped.draws(), n + 1);
+            assert_eq!(jumped.check_invariants(), Ok(()));
+            assert_eq!(jumped.uni().to_bits(), stepped.uni().to_bits());
+        }
+    }
+}
+
+#[test]
+fn jumps_compose() {
+    let mut once = seeded(Indexing::Port, 170);
+    once.discard(3_000_000_000);
+    let mut twice = seeded(Indexing::Port, 170);
+    twice.discard(1_000_000_000);
+    twice.discard(2_000_000_000);
+    assert_eq!(once, twice);
+    assert_eq!(once.draws(), 3_000_000_000);
+}
+
+#[test]
+fn huge_jumps_are_fast() {
+    let start = std::time::Instant::now();
+    let mut rng = seeded(Indexing::Ranmar, 170);
+    rng.discard(u64::MAX);
+    assert_eq!(rng.check_invariants(), Ok(()));
+    assert!(start.elapsed().as_secs() < 10);
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+#[should_panic(expected = "discard: generator used before rinit or rstart")]
+fn unseeded_generator_panics() {
+    unirand::MarsagliaUniRng::new().discard(10);
+}
@@ -0,0 +1,20 @@
+use unirand::science::dp::{add_laplace_noise, laplace};
+use unirand::MarsagliaUniRng;
+
+// With rinit(170), draw 5911993 is exactly 0.0.
+const ZERO_DRAW: u64 = 5_911_993;
+
+#[test]
+fn laplace_is_finite_when_uni_returns_zero() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    rng.discard(ZERO_DRAW);
+    assert_eq!(rng.clone().uni(), 0.0);
+    let x = laplace(&mut rng, 1.0);
+    assert!(x.is_finite(), "laplace gave {}", x);
+
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    rng.discard(ZERO_DRAW);
+    let mut values = [10.0];
+    add_laplace_noise(&mut rng, &mut values, 1.0, 1.0);
+    assert!(values[0].is_finite(), "noisy value is {}", values[0]);
+}
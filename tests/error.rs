@@ -0,0 +1,39 @@
+// Conversions into the crate-wide Error and its messages.
+
+use unirand::{Error, InvariantViolation, MarsagliaUniRng, RangeError};
+
+fn draw(rng: &mut MarsagliaUniRng, low: f64, high: f64) -> Result<f64, Error> {
+    let x = rng.gen_range_f64(low..high)?;
+    rng.check_invariants()?;
+    Ok(x)
+}
+
+#[test]
+fn question_mark_converts_module_errors() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    assert!(draw(&mut rng, 0.0, 1.0).is_ok());
+    assert_eq!(
+        draw(&mut rng, 1.0, 0.0),
+        Err(Error::Range(RangeError::Inverted))
+    );
+}
+
+#[test]
+fn display_names_the_area_and_detail() {
+    let e = Error::from(RangeError::Empty);
+    assert_eq!(e.to_string(), "invalid range: range is empty");
+    let e = Error::from(InvariantViolation::TableValueOutOfRange(4));
+    assert_eq!(
+        e.to_string(),
+        "invalid generator state: table entry 4 is outside [0, 1)"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn source_is_the_wrapped_error() {
+    use std::error::Error as _;
+    let e = Error::from(InvariantViolation::IndicesEqual);
+    let source = e.source().expect("a source");
+    assert_eq!(source.to_string(), "lag indices are equal");
+}
@@ -0,0 +1,54 @@
+use std::collections::HashSet;
+
+use unirand::{MarsagliaUniRng, StreamFamily, MAX_STREAMS};
+
+#[test]
+fn streams_have_distinct_valid_seeds() {
+    let family = StreamFamily::new(2024);
+    let mut seen = HashSet::new();
+    for id in (0..100_000).chain(MAX_STREAMS - 1000..MAX_STREAMS) {
+        let [i, j, k, l] = family.stream_seeds(id);
+        assert!(MarsagliaUniRng::unseeded().try_rstart(i, j, k, l).is_ok());
+        assert!(seen.insert([i, j, k, l]), "id {} repeats a seed", id);
+    }
+}
+
+#[test]
+fn streams_are_reproducible_and_keyed_by_the_master_seed() {
+    let a = StreamFamily::new(1);
+    let mut rng = a.stream(7);
+    let mut again = StreamFamily::new(1).stream(7);
+    assert_eq!(rng.seeds(), a.stream_seeds(7));
+    assert_eq!(rng.draws(), 0);
+    for _ in 0..100 {
+        assert_eq!(rng.uni().to_bits(), again.uni().to_bits());
+    }
+    assert_ne!(a.stream_seeds(7), StreamFamily::new(2).stream_seeds(7));
+    assert_ne!(a.stream_seeds(7), a.stream_seeds(8));
+    assert_eq!(a.master_seed(), 1);
+}
+
+#[test]
+fn neighbouring_streams_are_uncorrelated() {
+    let family = StreamFamily::new(42);
+    let (mut a, mut b) = (family.stream(0), family.stream(1));
+    let n = 100_000;
+    let (xs, ys): (Vec<f64>, Vec<f64>) = (0..n).map(|_| (a.uni() as f64, b.uni() as f64)).unzip();
+    let mean = |v: &[f64]| v.iter().sum::<f64>() / n as f64;
+    let (mx, my) = (mean(&xs), mean(&ys));
+    let cov: f64 = xs
+        .iter()
+        .zip(&ys)
+        .map(|(x, y)| (x - mx) * (y - my))
+        .sum::<f64>()
+        / n as f64;
+    // The correlation of independent uniforms has standard deviation 1/sqrt(n).
+    let corr = cov * 12.0;
+    assert!(corr.abs() < 4.0 / (n as f64).sqrt(), "corr = {}", corr);
+}
+
+#[test]
+#[should_panic(expected = "StreamFamily::stream: id = 953117919 -- out of range")]
+fn ids_beyond_the_seed_space_panic() {
+    StreamFamily::new(0).stream(MAX_STREAMS);
+}
@@ -0,0 +1,44 @@
+// Bulk fills reproduce the one-at-a-time stream exactly.
+
+mod common;
+
+use common::seeded;
+use unirand::Indexing;
+
+#[test]
+fn fill_f32_matches_uni() {
+    for indexing in [Indexing::Port, Indexing::Ranmar] {
+        let mut bulk = seeded(indexing, 170);
+        let mut single = seeded(indexing, 170);
+        // Uneven lengths so chunk boundaries fall at different table positions.
+        for len in [0, 1, 97, 98, 1_000, 4_321] {
+            let mut buf = vec![0.0f32; len];
+            bulk.fill_f32(&mut buf);
+            for x in &buf {
+                assert_eq!(x.to_bits(), single.uni().to_bits());
+            }
+        }
+        assert_eq!(bulk, single);
+        assert_eq!(bulk.draws(), single.draws());
+    }
+}
+
+#[test]
+fn fill_f64_matches_gen_range_f64() {
+    let mut bulk = seeded(Indexing::Port, 54217137);
+    let mut single = seeded(Indexing::Port, 54217137);
+    let mut buf = vec![0.0f64; 1_000];
+    bulk.fill_f64(&mut buf);
+    for x in &buf {
+        assert!((0.0..1.0).contains(x));
+        assert_eq!(Ok(*x), single.gen_range_f64(0.0..1.0));
+    }
+    assert_eq!(bulk, single);
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+#[should_panic(expected = "before rinit or rstart")]
+fn fill_f32_needs_a_seed() {
+    unirand::MarsagliaUniRng::new().fill_f32(&mut [0.0; 4]);
+}
@@ -0,0 +1,59 @@
+// Endpoints and error handling of gen_range_f32 and gen_range_f64.
+
+use unirand::{MarsagliaUniRng, RangeError};
+
+#[test]
+fn values_lie_in_half_open_range() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    for _ in 0..10_000 {
+        let x = rng.gen_range_f32(-2.5..4.0).unwrap();
+        assert!((-2.5..4.0).contains(&x));
+        let y = rng.gen_range_f64(1e-3..2e-3).unwrap();
+        assert!((1e-3..2e-3).contains(&y));
+    }
+}
+
+#[test]
+fn f32_matches_uni_scaled() {
+    let mut a = MarsagliaUniRng::from_seed(170);
+    let mut b = MarsagliaUniRng::from_seed(170);
+    for _ in 0..1_000 {
+        assert_eq!(a.gen_range_f32(3.0..7.0), Ok(b.uni_scaled(3.0, 7.0)));
+    }
+}
+
+#[test]
+fn extreme_and_narrow_ranges() {
+    let mut rng = MarsagliaUniRng::from_seed(54217137);
+    for _ in 0..10_000 {
+        let x = rng.gen_range_f64(-f64::MAX..f64::MAX).unwrap();
+        assert!(x.is_finite());
+        let one = 1.0f64;
+        assert_eq!(rng.gen_range_f64(one..one.next_up()), Ok(one));
+    }
+}
+
+#[test]
+fn f64_uses_more_than_24_bits() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let fine = (0..100)
+        .map(|_| rng.gen_range_f64(0.0..1.0).unwrap())
+        .filter(|u| (u * 16_777_216.0).fract() != 0.0)
+        .count();
+    assert!(fine > 90);
+}
+
+#[test]
+fn bad_ranges_are_errors() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    assert_eq!(rng.gen_range_f32(1.0..1.0), Err(RangeError::Empty));
+    assert_eq!(rng.gen_range_f32(2.0..1.0), Err(RangeError::Inverted));
+    assert_eq!(
+        rng.gen_range_f32(0.0..f32::INFINITY),
+        Err(RangeError::NotFinite)
+    );
+    assert_eq!(rng.gen_range_f64(f64::NAN..1.0), Err(RangeError::NotFinite));
+    assert_eq!(rng.gen_range_f64(0.0..-0.0), Err(RangeError::Empty));
+    // A rejected range consumes nothing.
+    assert_eq!(rng, MarsagliaUniRng::from_seed(170));
+}
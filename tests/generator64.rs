@@ -0,0 +1,65 @@
+// Range, resolution and seeding of the double-precision generator.
+
+use unirand::MarsagliaUniRng64;
+
+fn seeded(ijkl: i32) -> MarsagliaUniRng64 {
+    let mut rng = MarsagliaUniRng64::new();
+    rng.rinit(ijkl);
+    rng
+}
+
+#[test]
+fn outputs_are_53_bit_fractions_in_unit_interval() {
+    let mut rng = seeded(170);
+    let mut low_bits = 0u64;
+    for _ in 0..100_000 {
+        let x = rng.uni();
+        assert!((0.0..1.0).contains(&x), "{} outside [0, 1)", x);
+        let scaled = x * 9007199254740992.0;
+        assert_eq!(scaled, scaled.trunc(), "{} is not a multiple of 2^-53", x);
+        low_bits |= scaled as u64 & ((1 << 29) - 1);
+    }
+    // Bits below the single-precision 24 are all in use.
+    assert_eq!(low_bits, (1 << 29) - 1);
+}
+
+#[test]
+fn rinit_matches_decomposed_rstart() {
+    let ijkl = 1802 * 30082 + 9373;
+    let mut a = seeded(ijkl);
+    let mut b = MarsagliaUniRng64::new();
+    b.rstart(12, 34, 56, 78);
+    for _ in 0..1_000 {
+        assert_eq!(a.uni().to_bits(), b.uni().to_bits());
+    }
+}
+
+#[test]
+fn reseeding_restarts_the_stream() {
+    let mut rng = seeded(42);
+    let first: Vec<u64> = (0..500).map(|_| rng.next_word()).collect();
+    rng.rinit(42);
+    let again: Vec<u64> = (0..500).map(|_| rng.next_word()).collect();
+    assert_eq!(first, again);
+    assert_eq!(rng, {
+        let mut fresh = seeded(42);
+        for _ in 0..500 {
+            fresh.uni();
+        }
+        fresh
+    });
+}
+
+#[test]
+fn mean_is_near_one_half() {
+    let mut rng = seeded(9_000);
+    let n = 200_000;
+    let mean = (0..n).map(|_| rng.uni()).sum::<f64>() / n as f64;
+    assert!((mean - 0.5).abs() < 0.005, "mean {}", mean);
+}
+
+#[test]
+#[should_panic(expected = "before rinit or rstart")]
+fn unseeded_generator_panics() {
+    MarsagliaUniRng64::new().uni();
+}
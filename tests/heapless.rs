@@ -0,0 +1,46 @@
+// The slice-filling forms of the testdata helpers match the allocating ones.
+#![cfg(feature = "alloc")]
+
+use unirand::testdata::arrays::{adversarial_array, fill_adversarial_array, ArrayPattern};
+use unirand::testdata::sat::{random_clause, random_ksat};
+use unirand::testdata::unicode::{fill_random_unicode, random_unicode_string, UnicodeClass};
+use unirand::MarsagliaUniRng;
+
+#[test]
+fn arrays() {
+    let patterns = [
+        ArrayPattern::Random,
+        ArrayPattern::NearlySorted { swaps: 10 },
+        ArrayPattern::FewUnique { distinct: 3 },
+        ArrayPattern::Sawtooth {
+            period: 5,
+            noise: 2,
+        },
+        ArrayPattern::Duplicates { ratio: 0.5 },
+    ];
+    for pattern in patterns {
+        let mut out = [0u32; 64];
+        fill_adversarial_array(pattern, 170, &mut out);
+        assert_eq!(adversarial_array(pattern, 64, 170), out);
+    }
+}
+
+#[test]
+fn clauses() {
+    let clauses = random_ksat(&mut MarsagliaUniRng::from_seed(170), 20, 5, 3);
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    for clause in clauses {
+        let mut out = [0i32; 3];
+        random_clause(&mut rng, 20, &mut out);
+        assert_eq!(clause, out);
+    }
+}
+
+#[test]
+fn unicode() {
+    let classes = [UnicodeClass::Ascii, UnicodeClass::Emoji, UnicodeClass::Any];
+    let text = random_unicode_string(&mut MarsagliaUniRng::from_seed(170), 40, &classes);
+    let mut out = ['\0'; 40];
+    fill_random_unicode(&mut MarsagliaUniRng::from_seed(170), &mut out, &classes);
+    assert_eq!(text, out.iter().collect::<String>());
+}
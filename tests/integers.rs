@@ -0,0 +1,134 @@
+// Full-width integer output built from the 24-bit words.
+
+use unirand::MarsagliaUniRng;
+
+#[test]
+fn next_u32_packs_consecutive_words() {
+    let mut words = MarsagliaUniRng::from_seed(170);
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    for _ in 0..1_000 {
+        let (a, _) = words.fraction();
+        let (b, _) = words.fraction();
+        assert_eq!(rng.next_u32(), (a << 8) | (b >> 16));
+    }
+}
+
+#[test]
+fn next_u64_packs_three_words() {
+    let mut words = MarsagliaUniRng::from_seed(170);
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    for _ in 0..1_000 {
+        let [a, b, c] = [0; 3].map(|_| words.fraction().0 as u64);
+        assert_eq!(rng.next_u64(), (a << 40) | (b << 16) | (c >> 8));
+    }
+}
+
+#[test]
+fn every_bit_varies() {
+    let mut rng = MarsagliaUniRng::from_seed(54217137);
+    let (mut ones32, mut zeros32) = (0u32, 0u32);
+    let (mut ones64, mut zeros64) = (0u64, 0u64);
+    for _ in 0..1_000 {
+        let x = rng.next_u32();
+        ones32 |= x;
+        zeros32 |= !x;
+        let y = rng.next_u64();
+        ones64 |= y;
+        zeros64 |= !y;
+    }
+    assert_eq!((ones32, zeros32), (u32::MAX, u32::MAX));
+    assert_eq!((ones64, zeros64), (u64::MAX, u64::MAX));
+}
+
+#[test]
+fn gen_range_stays_in_bounds() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    for _ in 0..10_000 {
+        let x = rng.gen_range_u32(10..17);
+        assert!((10..17).contains(&x));
+        let y = rng.gen_range_u64(u64::MAX - 5..u64::MAX);
+        assert!((u64::MAX - 5..u64::MAX).contains(&y));
+    }
+    assert_eq!(rng.gen_range_u32(41..42), 41);
+    assert!(rng.gen_range_u32(0..u32::MAX) < u32::MAX);
+}
+
+#[test]
+fn gen_range_is_unbiased_for_awkward_spans() {
+    // A span just over 2^31 is where a plain modulo would give the low half
+    // twice the weight of the high half.
+    let mut rng = MarsagliaUniRng::from_seed(9_000);
+    let span = (1u32 << 31) + 1;
+    let n = 200_000;
+    let low = (0..n)
+        .filter(|_| rng.gen_range_u32(0..span) < span / 2)
+        .count();
+    let fraction = low as f64 / n as f64;
+    assert!((fraction - 0.5).abs() < 0.01, "fraction {}", fraction);
+
+    let counts = (0..60_000).fold([0u32; 6], |mut counts, _| {
+        counts[rng.gen_range_u64(0..6) as usize] += 1;
+        counts
+    });
+    assert!(
+        counts.iter().all(|c| (9_400..10_600).contains(c)),
+        "{:?}",
+        counts
+    );
+}
+
+#[test]
+fn inclusive_ranges_reach_both_ends() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    assert_eq!(rng.gen_range_inclusive_u64(7..=7), 7);
+    let mut a = MarsagliaUniRng::from_seed(3);
+    let mut b = MarsagliaUniRng::from_seed(3);
+    assert_eq!(a.gen_range_inclusive_u64(0..=u64::MAX), b.next_u64());
+    let (mut low, mut high) = (false, false);
+    for _ in 0..1_000 {
+        let x = rng.gen_range_inclusive_u64(u64::MAX - 3..=u64::MAX);
+        assert!(x >= u64::MAX - 3);
+        low |= x == u64::MAX - 3;
+        high |= x == u64::MAX;
+    }
+    assert!(low && high);
+}
+
+#[test]
+#[should_panic(expected = "is empty")]
+fn gen_range_rejects_empty_range() {
+    MarsagliaUniRng::from_seed(170).gen_range_u32(5..5);
+}
+
+#[test]
+fn limited_ranges_match_unlimited() {
+    let mut a = MarsagliaUniRng::from_seed(170);
+    let mut b = MarsagliaUniRng::from_seed(170);
+    for _ in 0..1_000 {
+        let span = (1u32 << 31) + 1;
+        assert_eq!(
+            a.gen_range_u32_limited(0..span, 64),
+            Ok(b.gen_range_u32(0..span))
+        );
+        assert_eq!(
+            a.gen_range_u64_limited(3..10, 64),
+            Ok(b.gen_range_u64(3..10))
+        );
+    }
+}
+
+#[test]
+fn limited_ranges_give_up() {
+    // Nearly half of all candidates are rejected for this span, so a limit of one
+    // draw fails often.
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let span = (1u32 << 31) + 1;
+    let failures = (0..1_000)
+        .filter(|_| rng.gen_range_u32_limited(0..span, 1).is_err())
+        .count();
+    assert!((400..600).contains(&failures), "{} failures", failures);
+    assert_eq!(
+        (0..100).find_map(|_| rng.gen_range_u32_limited(0..span, 1).err()),
+        Some(unirand::RejectionLimitError { limit: 1 })
+    );
+}
@@ -0,0 +1,132 @@
+// Checks the generator against an independent integer model of the recurrence.
+//
+// The model keeps the table and corrections as exact 24-bit integers and
+// spells out the index transitions, so any change to the hot loop that alters
+// the state sequence, or breaks an invariant, shows up here.
+
+use unirand::MarsagliaUniRng;
+
+const ONE: i64 = 1 << 24;
+
+struct Model {
+    u: [i64; 98],
+    c: i64,
+    ui: usize,
+    uj: usize,
+}
+
+impl Model {
+    fn new(ijkl: i32) -> Self {
+        let ij = ijkl / 30082;
+        let kl = ijkl - 30082 * ij;
+        let (mut i, mut j) = ((ij / 177) % 177 + 2, ij % 177 + 2);
+        let (mut k, mut l) = ((kl / 169) % 178 + 1, kl % 169);
+        let mut u = [0; 98];
+        for slot in u.iter_mut().skip(1) {
+            let mut t = ONE / 2;
+            for _ in 0..24 {
+                let m = ((i * j % 179) * k) % 179;
+                i = j;
+                j = k;
+                k = m;
+                l = (53 * l + 1) % 169;
+                if l * m % 64 >= 32 {
+                    *slot += t;
+                }
+                t /= 2;
+            }
+        }
+        Self {
+            u,
+            c: 362436,
+            ui: 97,
+            uj: 33,
+        }
+    }
+
+    fn step(&mut self) -> i64 {
+        let mut x = self.u[self.ui] - self.u[self.uj];
+        if x < 0 {
+            x += ONE;
+        }
+        self.u[self.ui] = x;
+        // Both indices walk down through every position 97, 96, ..., 0 and wrap to 97.
+        self.ui = if self.ui == 0 { 97 } else { self.ui - 1 };
+        self.uj = if self.uj == 0 { 97 } else { self.uj - 1 };
+        self.c -= 7654321;
+        if self.c < 0 {
+            self.c += 16777213;
+        }
+        x -= self.c;
+        if x < 0 {
+            x += ONE;
+        }
+        assert!(self.ui != self.uj, "model indices collided");
+        x
+    }
+}
+
+#[test]
+fn matches_integer_model_over_long_runs() {
+    for &seed in &[0, 1, 170, 54217137, 123_456_789, 900_000_000] {
+        let mut rng = MarsagliaUniRng::from_seed(seed);
+        let mut model = Model::new(seed);
+        for step in 0..100_000 {
+            let expected = model.step() as f32 / ONE as f32;
+            let actual = rng.uni();
+            assert_eq!(
+                actual.to_bits(),
+                expected.to_bits(),
+                "seed {} step {}",
+                seed,
+                step
+            );
+            if step % 97 == 0 {
+                assert_eq!(
+                    rng.check_invariants(),
+                    Ok(()),
+                    "seed {} step {}",
+                    seed,
+                    step
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn invariants_hold_on_every_step() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    assert_eq!(rng.check_invariants(), Ok(()));
+    for _ in 0..10_000 {
+        let x = rng.uni();
+        assert!((0.0..1.0).contains(&x));
+        assert_eq!(rng.check_invariants(), Ok(()));
+    }
+}
+
+#[test]
+fn invariants_hold_after_restore_and_reseed() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    for _ in 0..12_345 {
+        rng.uni();
+    }
+    let mut restored = rng.clone();
+    assert_eq!(restored.check_invariants(), Ok(()));
+    for _ in 0..1_000 {
+        assert_eq!(restored.uni().to_bits(), rng.uni().to_bits());
+        assert_eq!(restored.check_invariants(), Ok(()));
+    }
+    restored.rinit(42);
+    assert_eq!(restored.check_invariants(), Ok(()));
+    assert_eq!(restored, MarsagliaUniRng::from_seed(42));
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn unseeded_generator_fails_the_index_check() {
+    assert_eq!(
+        MarsagliaUniRng::new().check_invariants(),
+        Err(unirand::InvariantViolation::IndicesEqual)
+    );
+}
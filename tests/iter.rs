@@ -0,0 +1,43 @@
+// The iterator adapters yield the uni stream.
+
+use unirand::MarsagliaUniRng;
+
+#[test]
+fn iter_borrows_and_continues_the_stream() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let mut reference = MarsagliaUniRng::from_seed(170);
+    let first: Vec<f32> = rng.iter().take(100).collect();
+    let expected: Vec<f32> = (0..100).map(|_| reference.uni()).collect();
+    assert_eq!(first, expected);
+    assert_eq!(rng.uni(), reference.uni());
+}
+
+#[test]
+fn for_loops_over_mut_reference() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let mut reference = MarsagliaUniRng::from_seed(170);
+    for (n, x) in (&mut rng).into_iter().enumerate() {
+        assert_eq!(x, reference.uni());
+        if n == 9 {
+            break;
+        }
+    }
+    assert_eq!(rng, reference);
+}
+
+#[test]
+fn into_iter_owns_the_generator() {
+    let mut reference = MarsagliaUniRng::from_seed(42);
+    let pairs: Vec<(f32, usize)> = MarsagliaUniRng::from_seed(42)
+        .into_iter()
+        .zip(0..50)
+        .collect();
+    for (x, _) in &pairs {
+        assert_eq!(*x, reference.uni());
+    }
+    let mut it = MarsagliaUniRng::from_seed(42).into_iter();
+    let sum: f32 = it.by_ref().take(50).sum();
+    assert!(sum > 0.0);
+    let mut rng = it.into_inner();
+    assert_eq!(rng.uni(), reference.uni());
+}
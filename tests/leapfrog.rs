@@ -0,0 +1,42 @@
+use unirand::MarsagliaUniRng;
+
+#[test]
+fn streams_interleave_into_the_base_sequence() {
+    let mut base = MarsagliaUniRng::from_seed(170);
+    let expected: Vec<(u32, u32)> = (0..4 * 500).map(|_| base.fraction()).collect();
+    let mut streams: Vec<_> = (0..4)
+        .map(|k| MarsagliaUniRng::leapfrog(170, k, 4))
+        .collect();
+    for (n, value) in expected.iter().enumerate() {
+        assert_eq!(streams[n % 4].fraction(), *value, "draw {}", n);
+    }
+}
+
+#[test]
+fn wide_strides_match_discard() {
+    let mut stream = MarsagliaUniRng::leapfrog(54217137, 3, 10_000);
+    let mut base = MarsagliaUniRng::from_seed(54217137);
+    base.discard(3);
+    for _ in 0..20 {
+        assert_eq!(stream.uni().to_bits(), base.uni().to_bits());
+        base.discard(9_999);
+    }
+    assert_eq!(stream.stream_index(), 3);
+    assert_eq!(stream.num_streams(), 10_000);
+    assert_eq!(stream.into_inner(), base);
+}
+
+#[test]
+fn a_single_stream_is_the_base_sequence() {
+    let mut stream = MarsagliaUniRng::leapfrog(9, 0, 1);
+    let mut base = MarsagliaUniRng::from_seed(9);
+    for _ in 0..100 {
+        assert_eq!(stream.uni().to_bits(), base.uni().to_bits());
+    }
+}
+
+#[test]
+#[should_panic(expected = "leapfrog: stream_index = 4, num_streams = 4 -- out of range")]
+fn stream_index_must_be_below_num_streams() {
+    MarsagliaUniRng::leapfrog(170, 4, 4);
+}
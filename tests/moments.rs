@@ -0,0 +1,160 @@
+// Sample moments of every sampler against their analytic values.
+
+mod common;
+
+use common::{assert_moments, Moments};
+use unirand::distributions::{Exponential, Gumbel, LogNormal, Normal, Zipf};
+use unirand::{MarsagliaUniRng, MarsagliaUniRng64};
+
+// Draws n values from one-at-a-time sampler f.
+fn draws(seed: i32, n: usize, mut f: impl FnMut(&mut MarsagliaUniRng) -> f64) -> Vec<f64> {
+    let mut rng = MarsagliaUniRng::from_seed(seed);
+    (0..n).map(|_| f(&mut rng)).collect()
+}
+
+// Draws n values by filling one f32 buffer.
+fn filled(seed: i32, n: usize, f: impl FnOnce(&mut MarsagliaUniRng, &mut [f32])) -> Vec<f64> {
+    let mut rng = MarsagliaUniRng::from_seed(seed);
+    let mut buf = vec![0.0f32; n];
+    f(&mut rng, &mut buf);
+    buf.into_iter().map(f64::from).collect()
+}
+
+const UNIFORM: Moments = Moments {
+    mean: 0.5,
+    variance: 1.0 / 12.0,
+    skewness: 0.0,
+};
+
+fn normal(mean: f64, std_dev: f64) -> Moments {
+    Moments {
+        mean,
+        variance: std_dev * std_dev,
+        skewness: 0.0,
+    }
+}
+
+#[test]
+fn uniform() {
+    assert_moments("uni", UNIFORM, |s, n| draws(s, n, |r| r.uni() as f64));
+    assert_moments("fill_f32", UNIFORM, |s, n| {
+        filled(s, n, |r, b| r.fill_f32(b))
+    });
+    assert_moments("fill_f64", UNIFORM, |s, n| {
+        let mut buf = vec![0.0; n];
+        MarsagliaUniRng::from_seed(s).fill_f64(&mut buf);
+        buf
+    });
+    assert_moments("MarsagliaUniRng64", UNIFORM, |s, n| {
+        let mut rng = MarsagliaUniRng64::new();
+        rng.rinit(s);
+        (0..n).map(|_| rng.uni()).collect()
+    });
+    let scaled = Moments {
+        mean: 1.0,
+        variance: 9.0 / 12.0,
+        skewness: 0.0,
+    };
+    assert_moments("gen_range_f64", scaled, |s, n| {
+        draws(s, n, |r| r.gen_range_f64(-0.5..2.5).unwrap())
+    });
+}
+
+#[test]
+fn normal_samplers() {
+    let d = Normal::new(2.0, 3.0);
+    assert_moments("Normal::sample", normal(2.0, 3.0), |s, n| {
+        draws(s, n, |r| d.sample(r) as f64)
+    });
+    assert_moments("Normal::fill", normal(2.0, 3.0), |s, n| {
+        filled(s, n, |r, b| d.fill(r, b))
+    });
+    assert_moments("fill_normal_pairs", normal(0.0, 1.0), |s, n| {
+        filled(s, n, |r, b| r.fill_normal_pairs(b))
+    });
+}
+
+#[test]
+fn exponential() {
+    let lambda = 0.5;
+    let d = Exponential::new(lambda as f32);
+    let m = Moments {
+        mean: 1.0 / lambda,
+        variance: 1.0 / (lambda * lambda),
+        skewness: 2.0,
+    };
+    assert_moments("Exponential::sample", m, |s, n| {
+        draws(s, n, |r| d.sample(r) as f64)
+    });
+    assert_moments("Exponential::fill", m, |s, n| {
+        filled(s, n, |r, b| d.fill(r, b))
+    });
+}
+
+#[test]
+fn log_normal() {
+    let (mu, sigma) = (0.5f64, 0.25f64);
+    let d = LogNormal::new(mu as f32, sigma as f32);
+    let w = (sigma * sigma).exp();
+    let m = Moments {
+        mean: (mu + sigma * sigma / 2.0).exp(),
+        variance: (w - 1.0) * (2.0 * mu + sigma * sigma).exp(),
+        skewness: (w + 2.0) * (w - 1.0).sqrt(),
+    };
+    assert_moments("LogNormal::sample", m, |s, n| {
+        draws(s, n, |r| d.sample(r) as f64)
+    });
+    assert_moments("LogNormal::fill", m, |s, n| {
+        filled(s, n, |r, b| d.fill(r, b))
+    });
+}
+
+#[test]
+fn gumbel() {
+    let (mu, beta) = (1.0f64, 2.0f64);
+    let d = Gumbel::new(mu as f32, beta as f32);
+    let pi = std::f64::consts::PI;
+    let zeta3 = 1.202_056_903_159_594_2;
+    let m = Moments {
+        mean: mu + beta * 0.577_215_664_901_532_9,
+        variance: pi * pi * beta * beta / 6.0,
+        skewness: 12.0 * 6f64.sqrt() * zeta3 / pi.powi(3),
+    };
+    assert_moments("Gumbel::sample", m, |s, n| {
+        draws(s, n, |r| d.sample(r) as f64)
+    });
+    assert_moments("Gumbel::fill", m, |s, n| filled(s, n, |r, b| d.fill(r, b)));
+}
+
+#[test]
+fn zipf() {
+    let (n_ranks, s) = (50, 1.5);
+    let d = Zipf::new(n_ranks, s);
+    let weights: Vec<f64> = (1..=n_ranks).map(|k| (k as f64).powf(-s)).collect();
+    let total: f64 = weights.iter().sum();
+    let moment = |p: i32| -> f64 {
+        let mean = (1..=n_ranks)
+            .zip(&weights)
+            .map(|(k, w)| k as f64 * w)
+            .sum::<f64>()
+            / total;
+        (1..=n_ranks)
+            .zip(&weights)
+            .map(|(k, w)| (k as f64 - mean).powi(p) * w)
+            .sum::<f64>()
+            / total
+    };
+    let mean = (1..=n_ranks)
+        .zip(&weights)
+        .map(|(k, w)| k as f64 * w)
+        .sum::<f64>()
+        / total;
+    let m = Moments {
+        mean,
+        variance: moment(2),
+        skewness: moment(3) / moment(2).powf(1.5),
+    };
+    assert_moments("Zipf::sample", m, |seed, n| {
+        draws(seed, n, |r| d.sample(r) as f64)
+    });
+}
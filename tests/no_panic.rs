@@ -0,0 +1,51 @@
+// Once seeded, the hot path has no input that makes it panic: the generator at
+// the seed extremes and under both indexings, fills of every length, and the
+// free fill helpers given parameters the constructors would reject.
+
+mod common;
+
+use common::seeded;
+use unirand::Indexing;
+
+#[test]
+fn uni_runs_through_many_table_cycles() {
+    for indexing in [Indexing::Port, Indexing::Ranmar] {
+        for ijkl in [0, 1, 170, 900_000_000] {
+            let mut rng = seeded(indexing, ijkl);
+            for _ in 0..200_000 {
+                let x = rng.uni();
+                assert!((0.0..1.0).contains(&x));
+            }
+            assert_eq!(rng.check_invariants(), Ok(()));
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn fills_accept_every_length() {
+    use unirand::distributions::{Exponential, Gumbel, LogNormal, Normal};
+    let mut rng = seeded(Indexing::Port, 170);
+    for len in 0..9 {
+        let mut buf = vec![0.0f32; len];
+        Normal::new(0.0, 1.0).fill(&mut rng, &mut buf);
+        Exponential::new(2.0).fill(&mut rng, &mut buf);
+        LogNormal::new(0.0, 1.0).fill(&mut rng, &mut buf);
+        Gumbel::new(0.0, 1.0).fill(&mut rng, &mut buf);
+        rng.fill_normal_pairs(&mut buf);
+        assert!(buf.iter().all(|x| x.is_finite()));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn fill_helpers_do_not_validate() {
+    let mut rng = seeded(Indexing::Port, 170);
+    let mut buf = [0.0f32; 5];
+    rng.fill_normal(&mut buf, f32::NAN, 1.0);
+    assert!(buf.iter().all(|x| x.is_nan()));
+    rng.fill_normal(&mut buf, 0.0, -1.0);
+    assert!(buf.iter().all(|x| !x.is_nan()));
+    rng.fill_exponential(&mut buf, 0.0);
+    assert!(buf.iter().all(|x| !x.is_finite() || *x == 0.0));
+}
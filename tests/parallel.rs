@@ -0,0 +1,63 @@
+use unirand::{MarsagliaUniRng, StreamFamily};
+
+#[test]
+fn parallel_fill_matches_serial_for_any_thread_count() {
+    for len in [0, 1, 65_537, 1_000_003] {
+        let mut serial = MarsagliaUniRng::from_seed(170);
+        let mut expected = vec![0.0f32; len];
+        serial.fill_f32(&mut expected);
+        for threads in [1, 2, 3, 8] {
+            let mut rng = MarsagliaUniRng::from_seed(170);
+            let mut buf = vec![0.0f32; len];
+            rng.par_fill_f32_threads(&mut buf, threads);
+            assert!(
+                buf.iter()
+                    .zip(&expected)
+                    .all(|(a, b)| a.to_bits() == b.to_bits()),
+                "len {} threads {}",
+                len,
+                threads
+            );
+            assert_eq!(rng, serial);
+        }
+    }
+}
+
+#[test]
+fn default_thread_count_continues_the_stream() {
+    let mut rng = MarsagliaUniRng::from_seed(9);
+    let mut buf = vec![0.0f32; 500_000];
+    rng.par_fill_f32(&mut buf);
+    let mut serial = MarsagliaUniRng::from_seed(9);
+    serial.discard(500_000);
+    assert_eq!(rng.uni().to_bits(), serial.uni().to_bits());
+}
+
+#[test]
+fn family_fill_is_the_same_for_any_thread_count() {
+    let family = StreamFamily::new(170);
+    for len in [0, 1, 65_536, 200_001] {
+        // Block b of 65536 values comes from stream b.
+        let mut expected = vec![0.0f32; len];
+        for (b, block) in expected.chunks_mut(65_536).enumerate() {
+            family.stream(b as u64).fill_f32(block);
+        }
+        for threads in [1, 2, 3, 8] {
+            let mut buf = vec![0.0f32; len];
+            family.par_fill_f32_threads(&mut buf, threads);
+            assert!(
+                buf.iter()
+                    .zip(&expected)
+                    .all(|(a, b)| a.to_bits() == b.to_bits()),
+                "len {} threads {}",
+                len,
+                threads
+            );
+        }
+    }
+    let mut buf = vec![0.0f32; 100_000];
+    family.par_fill_f32(&mut buf);
+    let mut rng = family.stream(1);
+    rng.discard(70_000 - 65_536);
+    assert_eq!(buf[70_000].to_bits(), rng.uni().to_bits());
+}
@@ -0,0 +1,47 @@
+// Parameter validation by the distribution constructors.
+
+use unirand::distributions::{Exponential, Gumbel, LogNormal, Normal, ParamError, Zipf};
+use unirand::Error;
+
+fn rejects<T: std::fmt::Debug>(result: Result<T, ParamError>, distribution: &str, parameter: &str) {
+    let e = result.expect_err("parameters should be rejected");
+    assert_eq!((e.distribution, e.parameter), (distribution, parameter));
+}
+
+#[test]
+fn valid_parameters_construct() {
+    assert_eq!(Normal::try_new(1.0, 2.0), Ok(Normal::new(1.0, 2.0)));
+    assert_eq!(Exponential::try_new(0.5), Ok(Exponential::new(0.5)));
+    assert_eq!(LogNormal::try_new(0.0, 0.5), Ok(LogNormal::new(0.0, 0.5)));
+    assert_eq!(Zipf::try_new(10, 1.1), Ok(Zipf::new(10, 1.1)));
+    assert_eq!(Gumbel::try_new(-3.0, 1.0), Ok(Gumbel::new(-3.0, 1.0)));
+}
+
+#[test]
+fn invalid_parameters_name_the_culprit() {
+    rejects(Normal::try_new(f32::NAN, 1.0), "Normal", "mean");
+    rejects(Normal::try_new(0.0, -1.0), "Normal", "std_dev");
+    rejects(Normal::try_new(0.0, 0.0), "Normal", "std_dev");
+    rejects(Normal::try_new(0.0, f32::INFINITY), "Normal", "std_dev");
+    rejects(Exponential::try_new(0.0), "Exponential", "lambda");
+    rejects(LogNormal::try_new(0.0, f32::NAN), "LogNormal", "sigma");
+    rejects(LogNormal::try_new(0.0, 0.0), "LogNormal", "sigma");
+    rejects(Zipf::try_new(0, 1.0), "Zipf", "n");
+    rejects(Zipf::try_new(5, -0.5), "Zipf", "s");
+    rejects(Gumbel::try_new(0.0, 0.0), "Gumbel", "beta");
+}
+
+#[test]
+fn converts_into_crate_error() {
+    let e: Error = Exponential::try_new(-1.0).unwrap_err().into();
+    assert_eq!(
+        e.to_string(),
+        "invalid distribution parameter: Exponential: lambda is out of range"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Gumbel::new: mu = 0, beta = -1 -- out of range")]
+fn new_panics_with_the_parameters() {
+    Gumbel::new(0.0, -1.0);
+}
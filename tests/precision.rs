@@ -0,0 +1,139 @@
+// Cross-checks each distribution's f32 sampler against an f64 reference that
+// reads the same generator stream. The reference applies the same transform in
+// double precision, so the two samples should agree to within the two-sample
+// Kolmogorov-Smirnov bound; a precision-dependent bug, such as a logarithm of
+// zero or a lost tail, shows up as a large distance.
+
+use unirand::distributions::{Exponential, Gumbel, LogNormal, Normal, Zipf};
+use unirand::MarsagliaUniRng;
+
+const N: usize = 50_000;
+const SEEDS: [i32; 3] = [170, 54217137, 900_000_000];
+
+// The two-sample Kolmogorov-Smirnov distance between a and b.
+fn ks_distance(a: &[f64], b: &[f64]) -> f64 {
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    a.sort_by(f64::total_cmp);
+    b.sort_by(f64::total_cmp);
+    let (mut i, mut j, mut d) = (0, 0, 0.0f64);
+    while i < a.len() && j < b.len() {
+        let x = a[i].min(b[j]);
+        while i < a.len() && a[i] <= x {
+            i += 1;
+        }
+        while j < b.len() && b[j] <= x {
+            j += 1;
+        }
+        d = d.max((i as f64 / a.len() as f64 - j as f64 / b.len() as f64).abs());
+    }
+    d
+}
+
+// Fails if the two samples differ by more than the KS critical value at
+// alpha = 0.001, Bonferroni-corrected for the seeds tried.
+fn assert_agree(name: &str, single: &[f64], double: &[f64]) {
+    let alpha = 0.001 / SEEDS.len() as f64;
+    let scale =
+        ((single.len() + double.len()) as f64 / (single.len() * double.len()) as f64).sqrt();
+    let bound = (-0.5 * (alpha / 2.0).ln()).sqrt() * scale;
+    let d = ks_distance(single, double);
+    assert!(d < bound, "{}: KS distance {} exceeds {}", name, d, bound);
+}
+
+// Runs the f32 sampler and the f64 reference from identically seeded generators.
+fn cross_check(
+    name: &str,
+    mut single: impl FnMut(&mut MarsagliaUniRng) -> f64,
+    mut double: impl FnMut(&mut MarsagliaUniRng) -> f64,
+) {
+    for seed in SEEDS {
+        let mut a = MarsagliaUniRng::from_seed(seed);
+        let mut b = MarsagliaUniRng::from_seed(seed);
+        let xs: Vec<f64> = (0..N).map(|_| single(&mut a)).collect();
+        let ys: Vec<f64> = (0..N).map(|_| double(&mut b)).collect();
+        assert!(
+            xs.iter().all(|x| x.is_finite()),
+            "{}: non-finite f32 sample",
+            name
+        );
+        assert_agree(name, &xs, &ys);
+    }
+}
+
+fn uni64(rng: &mut MarsagliaUniRng) -> f64 {
+    rng.uni() as f64
+}
+
+// Marsaglia's polar method in f64, the first value of each pair.
+fn normal64(rng: &mut MarsagliaUniRng) -> f64 {
+    loop {
+        let u = 2.0 * uni64(rng) - 1.0;
+        let v = 2.0 * uni64(rng) - 1.0;
+        let s = u * u + v * v;
+        if s > 0.0 && s < 1.0 {
+            return u * (-2.0 * s.ln() / s).sqrt();
+        }
+    }
+}
+
+#[test]
+fn normal() {
+    let d = Normal::new(1.5, 2.0);
+    cross_check(
+        "Normal",
+        |r| d.sample(r) as f64,
+        |r| 1.5 + 2.0 * normal64(r),
+    );
+}
+
+#[test]
+fn exponential() {
+    let d = Exponential::new(0.25);
+    cross_check(
+        "Exponential",
+        |r| d.sample(r) as f64,
+        |r| -(1.0 - uni64(r)).ln() / 0.25,
+    );
+}
+
+#[test]
+fn log_normal() {
+    let d = LogNormal::new(0.5, 0.75);
+    cross_check(
+        "LogNormal",
+        |r| d.sample(r) as f64,
+        |r| (0.5 + 0.75 * normal64(r)).exp(),
+    );
+}
+
+#[test]
+fn gumbel() {
+    let d = Gumbel::new(-1.0, 3.0);
+    cross_check(
+        "Gumbel",
+        |r| d.sample(r) as f64,
+        |r| -1.0 - 3.0 * (-(uni64(r) + 0.5 / 16_777_216.0).ln()).ln(),
+    );
+}
+
+#[test]
+fn zipf() {
+    let (n, s) = (200, 1.2);
+    let d = Zipf::new(n, s);
+    let total: f64 = (1..=n).map(|k| (k as f64).powf(-s)).sum();
+    cross_check(
+        "Zipf",
+        |r| d.sample(r) as f64,
+        |r| {
+            let mut u = uni64(r) * total;
+            for k in 1..=n {
+                u -= (k as f64).powf(-s);
+                if u < 0.0 {
+                    return k as f64;
+                }
+            }
+            n as f64
+        },
+    );
+}
@@ -0,0 +1,117 @@
+// Agreement of Indexing::Ranmar with published RANMAR and GSL's seeding.
+
+mod common;
+
+use common::seeded;
+use unirand::{Indexing, MarsagliaUniRng, Unseeded};
+
+fn words(rng: &mut MarsagliaUniRng, n: usize) -> Vec<u32> {
+    (0..n).map(|_| rng.fraction().0).collect()
+}
+
+// James (1990): seeds ij = 1802, kl = 9373, then after 20000 draws the next
+// six values times 2^24 are these.
+#[test]
+fn james_test_vector() {
+    let mut rng = seeded(Indexing::Ranmar, 1802 * 30082 + 9373);
+    for _ in 0..20_000 {
+        rng.uni();
+    }
+    assert_eq!(
+        words(&mut rng, 6),
+        [6533892, 14220222, 7275067, 6172232, 8354498, 10633180]
+    );
+}
+
+#[test]
+fn port_is_default_and_diverges_at_first_wrap() {
+    assert_eq!(MarsagliaUniRng::unseeded().indexing(), Indexing::Port);
+    let port = words(&mut seeded(Indexing::Port, 170), 44);
+    let ranmar = words(&mut seeded(Indexing::Ranmar, 170), 44);
+    // uj starts at 33, so the 34th draw is the first to wrap.
+    assert_eq!(port[..33], ranmar[..33]);
+    assert_ne!(port[33..], ranmar[33..]);
+}
+
+#[test]
+fn gsl_seeding_matches_rinit() {
+    for seed in [1, 170, 54_217_137, 900_000_000] {
+        let gsl = MarsagliaUniRng::gsl_ranmar(seed as u64);
+        assert_eq!(gsl, seeded(Indexing::Ranmar, seed));
+        assert_eq!(gsl.indexing(), Indexing::Ranmar);
+    }
+    // GSL treats 0 as 1 and reduces ij modulo 31329.
+    assert_eq!(
+        MarsagliaUniRng::gsl_ranmar(0),
+        MarsagliaUniRng::gsl_ranmar(1)
+    );
+    assert_eq!(
+        MarsagliaUniRng::gsl_ranmar(12345 + 30082 * 31329),
+        MarsagliaUniRng::gsl_ranmar(12345)
+    );
+}
+
+#[test]
+fn reseeding_keeps_indexing() {
+    let mut rng = seeded(Indexing::Ranmar, 42);
+    rng.rstart(12, 34, 56, 78);
+    assert_eq!(rng.indexing(), Indexing::Ranmar);
+    let restored = MarsagliaUniRng::restore(&rng.checkpoint_compact());
+    assert_eq!(restored.indexing(), Indexing::Ranmar);
+}
+
+#[test]
+fn ranmar_indexing_never_uses_slot_zero() {
+    let mut rng = seeded(Indexing::Ranmar, 170);
+    let reference = rng.clone();
+    for _ in 0..10_000 {
+        rng.uni();
+        assert_eq!(rng.check_invariants(), Ok(()));
+    }
+    assert_ne!(rng, reference);
+}
+
+#[test]
+fn indexing_is_part_of_equality() {
+    let port = seeded(Indexing::Port, 170);
+    let ranmar = seeded(Indexing::Ranmar, 170);
+    assert_ne!(port, ranmar);
+    assert_ne!(port.state_digest(), ranmar.state_digest());
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn archive_rejects_ranmar_indexing() {
+    let mut out = Vec::new();
+    let mut rng = seeded(Indexing::Ranmar, 170);
+    assert!(unirand::formats::archive::write_archive(&mut out, &mut rng, 10).is_err());
+    assert!(out.is_empty());
+}
+
+#[test]
+fn cernlib_restart_resumes_the_stream() {
+    let mut rng = seeded(Indexing::Ranmar, 1802 * 30082 + 9373);
+    for _ in 0..20_000 {
+        rng.uni();
+    }
+    let (ijkl, ntot, ntot2) = rng.cernlib_seeds().expect("seeded by rinit");
+    assert_eq!((ijkl, ntot, ntot2), (1802 * 30082 + 9373, 20_000, 0));
+    let mut resumed = MarsagliaUniRng::restart_cernlib(ijkl, ntot, ntot2);
+    assert_eq!(resumed, rng);
+    assert_eq!(words(&mut resumed, 6)[0], 6533892);
+}
+
+#[test]
+fn cernlib_seeds_need_ranmar_rinit_seeds() {
+    assert_eq!(seeded(Indexing::Port, 170).cernlib_seeds(), None);
+    #[cfg(feature = "legacy")]
+    assert_eq!(MarsagliaUniRng::new().cernlib_seeds(), None);
+    let rng = Unseeded::with_indexing(Indexing::Ranmar).rstart(1, 2, 3, 4);
+    assert_eq!(rng.cernlib_seeds(), None);
+    for seed in [0, 170, 900_000_000] {
+        assert_eq!(
+            seeded(Indexing::Ranmar, seed).cernlib_seeds(),
+            Some((seed, 0, 0))
+        );
+    }
+}
@@ -0,0 +1,80 @@
+// Endpoint and extreme-range behaviour of uni_scaled.
+
+use unirand::MarsagliaUniRng;
+
+fn assert_in_range(a: f32, b: f32, draws: usize) -> Vec<f32> {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let values: Vec<f32> = (0..draws).map(|_| rng.uni_scaled(a, b)).collect();
+    for x in &values {
+        assert!(*x >= a && *x < b, "{} outside [{}, {})", x, a, b);
+    }
+    values
+}
+
+#[test]
+fn unit_range_reproduces_uni() {
+    let mut plain = MarsagliaUniRng::from_seed(170);
+    let mut scaled = MarsagliaUniRng::from_seed(170);
+    for _ in 0..10_000 {
+        assert_eq!(scaled.uni_scaled(0.0, 1.0).to_bits(), plain.uni().to_bits());
+    }
+}
+
+#[test]
+fn full_f32_range_does_not_overflow() {
+    let values = assert_in_range(-f32::MAX, f32::MAX, 10_000);
+    let negative = values.iter().filter(|x| **x < 0.0).count();
+    assert!(
+        (4_500..5_500).contains(&negative),
+        "{} negative of 10000",
+        negative
+    );
+}
+
+#[test]
+fn huge_offset_narrow_width() {
+    let (a, b) = (16_777_216.0, 16_777_220.0);
+    let values = assert_in_range(a, b, 10_000);
+    // Only the two representable values in [a, b) exist at this magnitude.
+    assert!(values.iter().all(|x| *x == a || *x == a + 2.0));
+    assert!(values.contains(&a) && values.contains(&(a + 2.0)));
+}
+
+#[test]
+fn single_value_range_returns_lower_endpoint() {
+    let a = 1.0f32;
+    let b = a.next_up();
+    assert!(assert_in_range(a, b, 1_000).iter().all(|x| *x == a));
+}
+
+#[test]
+fn subnormal_and_negative_ranges() {
+    assert_in_range(0.0, f32::MIN_POSITIVE, 10_000);
+    assert_in_range(-1e-40, 1e-40, 10_000);
+    let values = assert_in_range(-3.0, -2.0, 100_000);
+    let mean = values.iter().map(|x| *x as f64).sum::<f64>() / values.len() as f64;
+    assert!((mean + 2.5).abs() < 0.005, "mean {}", mean);
+}
+
+#[test]
+fn upper_endpoint_is_excluded_when_rounding_would_reach_it() {
+    // Close to b the f64 value can round to b in f32; it must come back below b.
+    let (a, b) = (0.0f32, 1e-30);
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    for _ in 0..100_000 {
+        assert!(rng.uni_scaled(a, b) < b);
+    }
+    assert_in_range(1.0, 1.0000002, 10_000);
+}
+
+#[test]
+#[should_panic(expected = "not a finite non-empty range")]
+fn empty_range_panics() {
+    MarsagliaUniRng::from_seed(170).uni_scaled(1.0, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "not a finite non-empty range")]
+fn infinite_range_panics() {
+    MarsagliaUniRng::from_seed(170).uni_scaled(0.0, f32::INFINITY);
+}
@@ -0,0 +1,91 @@
+use unirand::scheduling::{
+    constrained_assignment, perturb_schedule, pick_free_slot, pick_free_slots,
+};
+use unirand::{MarsagliaUniRng, RejectionLimitError};
+
+#[test]
+fn assignment_respects_capacity_and_conflicts() {
+    // Twelve papers, four reviewers with room for three each.
+    let slots = [3, 3, 3, 3];
+    let forbidden: Vec<(usize, usize)> = (0..12)
+        .map(|c| (c, c % 4))
+        .chain([(0, 1), (0, 2)])
+        .collect();
+    for seed in 0..20 {
+        let mut rng = MarsagliaUniRng::from_seed(seed);
+        let assignment = constrained_assignment(&mut rng, 12, &slots, &forbidden, 100).unwrap();
+        assert_eq!(assignment[0], 3);
+        for (c, s) in assignment.iter().enumerate() {
+            assert!(
+                !forbidden.contains(&(c, *s)),
+                "seed {}: {} in {}",
+                seed,
+                c,
+                s
+            );
+        }
+        for s in 0..4 {
+            assert_eq!(assignment.iter().filter(|x| **x == s).count(), 3);
+        }
+    }
+}
+
+#[test]
+fn assignment_is_reproducible_and_varies_by_seed() {
+    let run = |seed| {
+        constrained_assignment(
+            &mut MarsagliaUniRng::from_seed(seed),
+            30,
+            &[10, 10, 10],
+            &[],
+            1,
+        )
+        .unwrap()
+    };
+    assert_eq!(run(170), run(170));
+    assert!((1..10).any(|seed| run(seed) != run(170)));
+}
+
+#[test]
+fn impossible_constraints_hit_the_attempt_limit() {
+    // Candidate 0 may not go in either slot.
+    let mut rng = MarsagliaUniRng::from_seed(1);
+    assert_eq!(
+        constrained_assignment(&mut rng, 2, &[1, 1], &[(0, 0), (0, 1)], 5),
+        Err(RejectionLimitError { limit: 5 })
+    );
+}
+
+#[test]
+#[should_panic(expected = "constrained_assignment: 5 candidates exceed the slot capacity")]
+fn too_few_places_panics() {
+    constrained_assignment(&mut MarsagliaUniRng::from_seed(1), 5, &[2, 2], &[], 10).ok();
+}
+
+#[test]
+fn perturbed_events_stay_within_the_shift() {
+    let schedule: Vec<i64> = (0..1000).map(|i| i * 10).collect();
+    let mut rng = MarsagliaUniRng::from_seed(9);
+    let shifted = perturb_schedule(&mut rng, &schedule, 3);
+    let shifts: Vec<i64> = shifted.iter().zip(&schedule).map(|(a, b)| a - b).collect();
+    assert!(shifts.iter().all(|d| (-3..=3).contains(d)));
+    for d in -3..=3 {
+        assert!(shifts.contains(&d), "shift {} never drawn", d);
+    }
+    assert_eq!(perturb_schedule(&mut rng, &schedule, 0), schedule);
+}
+
+#[test]
+fn free_slot_pickers_avoid_busy_slots() {
+    let busy = [0, 2, 3, 7];
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    for _ in 0..200 {
+        let slot = pick_free_slot(&mut rng, 8, &busy).unwrap();
+        assert!(slot < 8 && !busy.contains(&slot));
+    }
+    let mut picked = pick_free_slots(&mut rng, 8, &busy, 4).unwrap();
+    picked.sort_unstable();
+    assert_eq!(picked, [1, 4, 5, 6]);
+    assert_eq!(pick_free_slots(&mut rng, 8, &busy, 5), None);
+    assert_eq!(pick_free_slot(&mut rng, 2, &[0, 1]), None);
+}
@@ -0,0 +1,137 @@
+// Fallible seeding reports the component that was out of range.
+
+use unirand::{Error, Indexing, MarsagliaUniRng, SeedError, Unseeded};
+
+#[test]
+fn try_rinit_matches_rinit() {
+    for seed in [0, 170, 54217137, 900_000_000] {
+        let mut a = MarsagliaUniRng::from_seed(1);
+        let mut b = MarsagliaUniRng::unseeded().rinit(seed);
+        assert_eq!(a.try_rinit(seed), Ok(()));
+        assert_eq!(a, b);
+        assert_eq!(a.uni(), b.uni());
+    }
+}
+
+#[test]
+fn try_rinit_rejects_out_of_range_seeds() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    assert_eq!(rng.try_rinit(-1), Err(SeedError::Ijkl(-1)));
+    assert_eq!(
+        rng.try_rinit(900_000_001),
+        Err(SeedError::Ijkl(900_000_001))
+    );
+    // A failed seeding leaves the generator as it was.
+    assert_eq!(rng, MarsagliaUniRng::from_seed(170));
+}
+
+#[test]
+fn try_rstart_checks_each_component() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    assert_eq!(rng.try_rstart(0, 34, 56, 78), Err(SeedError::I(0)));
+    assert_eq!(rng.try_rstart(12, 179, 56, 78), Err(SeedError::J(179)));
+    assert_eq!(rng.try_rstart(12, 34, -5, 78), Err(SeedError::K(-5)));
+    assert_eq!(rng.try_rstart(12, 34, 56, 169), Err(SeedError::L(169)));
+    assert_eq!(rng.try_rstart(1, 1, 1, 78), Err(SeedError::AllOnes));
+    assert_eq!(rng.try_rstart(12, 34, 56, 78), Ok(()));
+    assert_eq!(rng.seeds(), [12, 34, 56, 78]);
+}
+
+#[test]
+fn converts_into_crate_error() {
+    let e: Error = MarsagliaUniRng::unseeded()
+        .try_rinit(-7)
+        .unwrap_err()
+        .into();
+    assert_eq!(e.to_string(), "seed ijkl = -7 is outside 0..=900000000");
+}
+
+#[test]
+#[should_panic(expected = "rinit: ijkl = -1 -- out of range")]
+fn rinit_still_panics() {
+    MarsagliaUniRng::unseeded().rinit(-1);
+}
+
+#[test]
+fn from_seed_is_unseeded_then_rinit() {
+    let two_step = MarsagliaUniRng::unseeded().rinit(170);
+    assert_eq!(MarsagliaUniRng::from_seed(170), two_step);
+    assert_eq!(MarsagliaUniRng::try_from_seed(170), Ok(two_step));
+    assert_eq!(MarsagliaUniRng::try_from_seed(-3), Err(SeedError::Ijkl(-3)));
+}
+
+#[cfg(not(feature = "legacy"))]
+#[test]
+fn new_returns_an_unseeded_generator() {
+    let unseeded: Unseeded = MarsagliaUniRng::new();
+    assert_eq!(unseeded, MarsagliaUniRng::unseeded());
+    assert_eq!(unseeded.rinit(170), MarsagliaUniRng::from_seed(170));
+}
+
+#[test]
+fn seeding_keeps_the_unseeded_indexing() {
+    assert_eq!(Unseeded::new().indexing(), Indexing::Port);
+    let ranmar = Unseeded::with_indexing(Indexing::Ranmar).rstart(12, 34, 56, 78);
+    assert_eq!(ranmar.indexing(), Indexing::Ranmar);
+    assert_eq!(ranmar.seeds(), [12, 34, 56, 78]);
+}
+
+#[test]
+fn unseeded_try_seeding_reports_errors() {
+    assert_eq!(
+        Unseeded::new().try_rinit(900_000_001),
+        Err(SeedError::Ijkl(900_000_001))
+    );
+    assert_eq!(
+        Unseeded::new().try_rstart(1, 1, 1, 78),
+        Err(SeedError::AllOnes)
+    );
+    assert_eq!(
+        Unseeded::new().try_rinit(170),
+        Ok(MarsagliaUniRng::from_seed(170))
+    );
+}
+
+#[test]
+fn any_u64_gives_valid_seeds() {
+    for seed in (0..10_000u64).chain([u64::MAX, 1 << 63, 0xdead_beef]) {
+        let [i, j, k, l] = MarsagliaUniRng::from_u64(seed).seeds();
+        assert!(MarsagliaUniRng::unseeded().try_rstart(i, j, k, l).is_ok());
+    }
+    assert_ne!(
+        MarsagliaUniRng::from_u64(1).seeds(),
+        MarsagliaUniRng::from_u64(2).seeds()
+    );
+}
+
+#[test]
+fn u64_and_byte_mappings_are_pinned() {
+    // These values are part of the stable API; they must never change.
+    assert_eq!(MarsagliaUniRng::from_u64(0).seeds(), [166, 169, 144, 155]);
+    assert_eq!(
+        MarsagliaUniRng::from_u64(u64::MAX).seeds(),
+        [25, 6, 132, 98]
+    );
+    assert_eq!(
+        MarsagliaUniRng::from_bytes(*b"experiment").seeds(),
+        [131, 98, 104, 167]
+    );
+    assert_eq!(
+        MarsagliaUniRng::from_bytes([]).seeds(),
+        [166, 169, 144, 155]
+    );
+}
+
+#[test]
+fn string_labels_seed_reproducibly() {
+    let mut a = MarsagliaUniRng::from_seed_str("experiment-42-baseline");
+    let mut b = MarsagliaUniRng::from_seed_str("experiment-42-baseline");
+    let mut c = MarsagliaUniRng::from_seed_str("experiment-42-treatment");
+    let xs: Vec<f32> = (0..100).map(|_| a.uni()).collect();
+    assert_eq!(xs, (0..100).map(|_| b.uni()).collect::<Vec<_>>());
+    assert_ne!(xs, (0..100).map(|_| c.uni()).collect::<Vec<_>>());
+    assert_eq!(
+        MarsagliaUniRng::from_seed_str("experiment").seeds(),
+        MarsagliaUniRng::from_bytes(*b"experiment").seeds()
+    );
+}
@@ -0,0 +1,109 @@
+use unirand::{
+    Endian, Error, Indexing, InvariantViolation, MarsagliaUniRng, StateError, Unseeded, STATE_LEN,
+    STATE_VERSION,
+};
+
+fn advanced(indexing: Indexing) -> MarsagliaUniRng {
+    let mut rng = Unseeded::with_indexing(indexing).rinit(54217137);
+    for _ in 0..12_345 {
+        rng.uni();
+    }
+    rng
+}
+
+#[test]
+fn restored_state_continues_the_sequence() {
+    for indexing in [Indexing::Port, Indexing::Ranmar] {
+        let mut rng = advanced(indexing);
+        let mut restored = MarsagliaUniRng::restore_state(&rng.save_state()).unwrap();
+        assert_eq!(restored, rng);
+        assert_eq!(restored.draws(), 12_345);
+        for _ in 0..1000 {
+            assert_eq!(restored.uni().to_bits(), rng.uni().to_bits());
+        }
+    }
+}
+
+#[cfg(feature = "legacy")]
+#[test]
+fn unseeded_state_round_trips() {
+    let rng = MarsagliaUniRng::with_indexing(Indexing::Ranmar);
+    assert_eq!(MarsagliaUniRng::restore_state(&rng.save_state()), Ok(rng));
+}
+
+#[cfg(not(feature = "legacy"))]
+#[test]
+fn unseeded_state_is_rejected() {
+    let mut bytes = MarsagliaUniRng::from_seed(170).save_state();
+    bytes[7] = 0;
+    assert_eq!(
+        MarsagliaUniRng::restore_state(&bytes),
+        Err(StateError::BadField(7))
+    );
+}
+
+#[test]
+fn layout_is_little_endian_numerators() {
+    let rng = MarsagliaUniRng::from_seed(170);
+    let bytes = rng.save_state();
+    assert_eq!(bytes.len(), STATE_LEN);
+    assert_eq!(&bytes[0..4], b"UNRS");
+    assert_eq!(bytes[4..6], STATE_VERSION.to_le_bytes());
+    assert_eq!(bytes[6..8], [0, 1]);
+    // rstart leaves slot 0 empty and fills the others with 24-bit fractions.
+    let table: Vec<u32> = bytes[8..400]
+        .chunks_exact(4)
+        .map(|c| u32::from_le_bytes(c.try_into().unwrap()))
+        .collect();
+    assert_eq!(table[0], 0);
+    assert!(table[1..].iter().all(|n| *n > 0 && *n < 1 << 24));
+    assert_eq!(bytes[400..404], 362436u32.to_le_bytes());
+    assert_eq!(bytes[404..408], 7654321u32.to_le_bytes());
+    assert_eq!(bytes[408..412], 16777213u32.to_le_bytes());
+    assert_eq!(bytes[412..416], [97, 0, 33, 0]);
+    assert_eq!(bytes[416..420], rng.seeds()[0].to_le_bytes());
+    assert_eq!(bytes[432..440], [0; 8]);
+}
+
+#[test]
+fn big_endian_snapshots_reverse_every_integer_and_restore() {
+    let rng = advanced(Indexing::Ranmar);
+    let little = rng.save_state();
+    let big = rng.save_state_endian(Endian::Big);
+    assert_eq!(big[0..4], little[0..4]);
+    assert_eq!(big[4..6], STATE_VERSION.to_be_bytes());
+    assert_eq!(big[6..8], little[6..8]);
+    for (at, width) in [(8, 4), (400, 4), (412, 2), (416, 4), (432, 8)] {
+        let mut field = big[at..at + width].to_vec();
+        field.reverse();
+        assert_eq!(field, little[at..at + width], "field at {}", at);
+    }
+    assert_eq!(MarsagliaUniRng::restore_state(&big), Ok(rng));
+}
+
+#[test]
+fn bad_snapshots_are_rejected() {
+    let good = advanced(Indexing::Port).save_state();
+    let corrupt = |at: usize, byte: u8| {
+        let mut bytes = good;
+        bytes[at] = byte;
+        MarsagliaUniRng::restore_state(&bytes)
+    };
+    assert_eq!(corrupt(0, b'X'), Err(StateError::BadMagic));
+    assert_eq!(corrupt(4, 9), Err(StateError::UnsupportedVersion(9)));
+    assert_eq!(corrupt(6, 2), Err(StateError::BadField(6)));
+    assert_eq!(corrupt(7, 2), Err(StateError::BadField(7)));
+    // A table numerator of 2^24 or more is a value outside [0, 1).
+    assert_eq!(
+        corrupt(8 + 4 * 5 + 3, 1),
+        Err(StateError::Invariant(
+            InvariantViolation::TableValueOutOfRange(5)
+        ))
+    );
+    assert_eq!(
+        corrupt(412, 200),
+        Err(StateError::Invariant(InvariantViolation::IndexOutOfRange))
+    );
+    let err: Error = corrupt(0, 0).unwrap_err().into();
+    assert_eq!(err, Error::State(StateError::BadMagic));
+}
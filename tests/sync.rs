@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use std::thread;
+
+use unirand::{MarsagliaUniRng, SyncMarsagliaUniRng};
+
+#[test]
+fn threads_share_one_stream() {
+    let shared = Arc::new(SyncMarsagliaUniRng::new(MarsagliaUniRng::from_seed(170)));
+    let workers: Vec<_> = (0..4)
+        .map(|_| {
+            let shared = Arc::clone(&shared);
+            thread::spawn(move || {
+                let mut batch = [0.0; 100];
+                shared.fill_f32(&mut batch);
+                let single: Vec<f32> = (0..100).map(|_| shared.uni()).collect();
+                (batch, single)
+            })
+        })
+        .collect();
+    let mut drawn: Vec<u32> = Vec::new();
+    for worker in workers {
+        let (batch, single) = worker.join().unwrap();
+        drawn.extend(batch.iter().chain(&single).map(|x| x.to_bits()));
+    }
+    // Every value of the first 800 was handed out exactly once.
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let mut expected: Vec<u32> = (0..800).map(|_| rng.uni().to_bits()).collect();
+    drawn.sort_unstable();
+    expected.sort_unstable();
+    assert_eq!(drawn, expected);
+    assert_eq!(Arc::try_unwrap(shared).unwrap().into_inner(), rng);
+}
+
+#[test]
+fn with_draws_consecutive_values_and_survives_panics() {
+    let shared = SyncMarsagliaUniRng::from(MarsagliaUniRng::from_seed(9));
+    let mut rng = MarsagliaUniRng::from_seed(9);
+    let pair = shared.with(|r| (r.uni(), r.uni()));
+    assert_eq!(pair, (rng.uni(), rng.uni()));
+    let result = thread::scope(|s| {
+        s.spawn(|| {
+            shared.with(|r| {
+                r.uni();
+                panic!("worker failed");
+            })
+        })
+        .join()
+    });
+    assert!(result.is_err());
+    rng.uni();
+    assert_eq!(shared.snapshot(), rng);
+    assert_eq!(shared.uni(), rng.uni());
+}
+
+#[test]
+fn is_send_and_sync() {
+    fn check<T: Send + Sync>() {}
+    check::<SyncMarsagliaUniRng>();
+}
@@ -0,0 +1,33 @@
+use unirand::{range, seed_thread_rng, thread_rng_seed, uni, with_thread_rng, MarsagliaUniRng};
+
+#[test]
+fn free_functions_draw_from_the_thread_generator() {
+    seed_thread_rng(170);
+    assert_eq!(thread_rng_seed(), 170);
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    assert_eq!(uni().to_bits(), rng.uni().to_bits());
+    assert_eq!(range(0..10), rng.gen_range_u32(0..10));
+    assert_eq!(with_thread_rng(|t| t.draws()), rng.draws());
+    assert_eq!(with_thread_rng(|t| t.next_u64()), rng.next_u64());
+}
+
+#[test]
+fn lazy_seeds_are_reported_and_differ_between_threads() {
+    let seeds: Vec<(i32, f32)> = (0..4)
+        .map(|_| std::thread::spawn(|| (thread_rng_seed(), uni())))
+        .map(|t| t.join().unwrap())
+        .collect();
+    for (seed, first) in &seeds {
+        assert!((0..=900_000_000).contains(seed));
+        assert_eq!(*first, MarsagliaUniRng::from_seed(*seed).uni());
+    }
+    assert!(seeds.iter().any(|s| s.0 != seeds[0].0));
+}
+
+#[test]
+fn values_are_in_range() {
+    for _ in 0..1000 {
+        assert!((0.0..1.0).contains(&uni()));
+        assert!((5..8).contains(&range(5..8)));
+    }
+}
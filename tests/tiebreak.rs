@@ -0,0 +1,56 @@
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use unirand::sampling::{argsort_with_random_ties, random_tiebreak_sort};
+use unirand::MarsagliaUniRng;
+
+#[test]
+fn sorts_by_key_and_reproduces_per_seed() {
+    let scores = [3, 1, 3, 2, 1, 3, 0, 2];
+    let sort = |seed| {
+        let mut teams: Vec<(char, i32)> = ('a'..).zip(scores).collect();
+        random_tiebreak_sort(&mut MarsagliaUniRng::from_seed(seed), &mut teams, |t| {
+            Reverse(t.1)
+        });
+        teams
+    };
+    let ranked = sort(170);
+    assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+    assert_eq!(ranked, sort(170));
+    assert!((1..50).any(|seed| sort(seed) != ranked));
+}
+
+#[test]
+fn argsort_matches_the_in_place_sort() {
+    let values = [2.5f64, 1.0, 2.5, 2.5, 0.5, 1.0];
+    let key = |x: &f64| x.to_bits();
+    for seed in 0..20 {
+        let order = argsort_with_random_ties(&mut MarsagliaUniRng::from_seed(seed), &values, key);
+        let mut indexed: Vec<(usize, f64)> = values.iter().copied().enumerate().collect();
+        random_tiebreak_sort(&mut MarsagliaUniRng::from_seed(seed), &mut indexed, |p| {
+            key(&p.1)
+        });
+        assert_eq!(order, indexed.iter().map(|p| p.0).collect::<Vec<_>>());
+    }
+}
+
+#[test]
+fn every_tie_order_is_equally_likely() {
+    // Three tied elements have six orders, each expected trials / 6 times.
+    let trials = 60_000;
+    let mut rng = MarsagliaUniRng::from_seed(54217137);
+    let mut counts = HashMap::new();
+    for _ in 0..trials {
+        let order = argsort_with_random_ties(&mut rng, &[7, 7, 7, 1], |x| *x);
+        assert_eq!(order[0], 3);
+        *counts.entry(order).or_insert(0) += 1;
+    }
+    assert_eq!(counts.len(), 6);
+    let expected = trials as f64 / 6.0;
+    let chi2: f64 = counts
+        .values()
+        .map(|c| (*c as f64 - expected).powi(2) / expected)
+        .sum();
+    // The 0.999 quantile of chi-squared with 5 degrees of freedom.
+    assert!(chi2 < 20.52, "chi2 = {}", chi2);
+}
@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use unirand::tournament::{random_bracket, round_robin_schedule_shuffled, swiss_pairings};
+use unirand::MarsagliaUniRng;
+
+#[test]
+fn bracket_places_every_team_once_with_separate_byes() {
+    for n in 2..=33 {
+        let bracket = random_bracket(&mut MarsagliaUniRng::from_seed(170), n);
+        assert_eq!(bracket.len(), n.next_power_of_two() / 2);
+        let mut seen: Vec<usize> = bracket
+            .iter()
+            .flat_map(|(a, b)| std::iter::once(*a).chain(*b))
+            .collect();
+        seen.sort_unstable();
+        assert_eq!(seen, (0..n).collect::<Vec<_>>());
+        let byes = bracket.iter().filter(|(_, b)| b.is_none()).count();
+        assert_eq!(byes, n.next_power_of_two() - n);
+        assert_eq!(
+            bracket,
+            random_bracket(&mut MarsagliaUniRng::from_seed(170), n)
+        );
+    }
+}
+
+#[test]
+fn round_robin_meets_every_pair_once() {
+    for n in 2..=12 {
+        let rounds = round_robin_schedule_shuffled(&mut MarsagliaUniRng::from_seed(9), n);
+        assert_eq!(rounds.len(), n + n % 2 - 1);
+        let mut pairs = HashSet::new();
+        for round in &rounds {
+            assert_eq!(round.len(), n / 2);
+            let mut playing = HashSet::new();
+            for (home, away) in round {
+                assert!(playing.insert(*home) && playing.insert(*away));
+                assert!(pairs.insert((*home.min(away), *home.max(away))));
+            }
+        }
+        assert_eq!(pairs.len(), n * (n - 1) / 2);
+    }
+    // Labels, round order and home sides all vary with the seed.
+    let a = round_robin_schedule_shuffled(&mut MarsagliaUniRng::from_seed(1), 8);
+    let b = round_robin_schedule_shuffled(&mut MarsagliaUniRng::from_seed(2), 8);
+    assert_ne!(a, b);
+}
+
+#[test]
+fn swiss_pairs_by_score_and_avoids_rematches() {
+    let scores = [3, 0, 2, 3, 1, 2, 0];
+    let mut rng = MarsagliaUniRng::from_seed(54217137);
+    let (pairs, bye) = swiss_pairings(&mut rng, &scores, &[]);
+    assert_eq!(pairs.len(), 3);
+    assert!(matches!(bye, Some(1 | 6)));
+    assert!(pairs[0] == (0, 3) || pairs[0] == (3, 0));
+
+    // Players 0 and 3 have met, so the leaders are paired elsewhere.
+    let (pairs, _) = swiss_pairings(&mut rng, &scores, &[(3, 0)]);
+    assert!(pairs.iter().all(|(a, b)| (*a.min(b), *a.max(b)) != (0, 3)));
+    let mut seen: Vec<usize> = pairs.iter().flat_map(|(a, b)| [*a, *b]).collect();
+    seen.sort_unstable();
+    seen.dedup();
+    assert_eq!(seen.len(), 6);
+}
+
+#[test]
+#[should_panic(expected = "random_bracket: n_teams = 1 -- out of range")]
+fn one_team_bracket_panics() {
+    random_bracket(&mut MarsagliaUniRng::from_seed(1), 1);
+}
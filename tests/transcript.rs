@@ -0,0 +1,62 @@
+use unirand::sampling::{draw_with_transcript, DrawTranscript};
+
+#[test]
+fn transcript_reproduces_the_winners() {
+    let entrants: Vec<String> = (0..50).map(|i| format!("ticket-{}", i)).collect();
+    let (winners, transcript) = draw_with_transcript(170, &entrants, 5);
+    assert_eq!(winners.len(), 5);
+    assert_eq!(transcript.swaps.len(), 5);
+    for (winner, index) in winners.iter().zip(&transcript.winners) {
+        assert_eq!(*winner, entrants[*index]);
+    }
+    let mut distinct = transcript.winners.clone();
+    distinct.sort_unstable();
+    distinct.dedup();
+    assert_eq!(distinct.len(), 5);
+    assert!(transcript.verify());
+    assert_eq!(draw_with_transcript(170, &entrants, 5).1, transcript);
+}
+
+#[test]
+fn altered_transcripts_fail_verification() {
+    let (_, transcript) = draw_with_transcript(54217137, &[(); 20], 3);
+    let altered = |change: fn(&mut DrawTranscript)| {
+        let mut t = transcript.clone();
+        change(&mut t);
+        t.verify()
+    };
+    assert!(!altered(|t| t.winners[0] = (t.winners[0] + 1) % 20));
+    assert!(!altered(|t| t.swaps.reverse()));
+    assert!(!altered(|t| t.seed += 1));
+    assert!(!altered(|t| t.seed = -1));
+    assert!(!altered(|t| t.k = 21));
+    // Neither a huge population nor a huge k makes verification allocate for it.
+    assert!(!altered(|t| t.population = usize::MAX));
+    assert!(!altered(|t| (t.population, t.k) = (usize::MAX, usize::MAX)));
+}
+
+#[test]
+fn transcript_prints_one_field_per_line() {
+    let (_, transcript) = draw_with_transcript(9, &[1, 2, 3, 4], 2);
+    let text = transcript.to_string();
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(
+        lines[..4],
+        ["unirand draw", "seed 9", "population 4", "k 2"]
+    );
+    assert!(lines[4].starts_with("swaps "));
+    assert!(lines[5].starts_with("winners "));
+}
+
+#[test]
+fn drawing_every_item_shuffles_them() {
+    let (winners, transcript) = draw_with_transcript(1, &[10, 20, 30], 3);
+    let mut sorted = winners.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, [10, 20, 30]);
+    assert!(transcript.verify());
+    assert_eq!(
+        draw_with_transcript(1, &[10, 20, 30], 0).0,
+        Vec::<i32>::new()
+    );
+}
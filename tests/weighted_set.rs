@@ -0,0 +1,142 @@
+// Frequencies and bookkeeping of CumulativeSampler and DynamicWeightedSet under updates.
+
+use unirand::sampling::{CumulativeSampler, DynamicWeightedSet};
+use unirand::stats::chi_square_p_value;
+use unirand::MarsagliaUniRng;
+
+const DRAWS: usize = 60_000;
+
+// Chi-square p-value of the set's sampling frequencies against its weights.
+fn fit(set: &DynamicWeightedSet<usize>, ids: &[usize], rng: &mut MarsagliaUniRng) -> f64 {
+    let mut counts = vec![0usize; ids.len()];
+    for _ in 0..DRAWS {
+        let (id, item) = set.sample(rng).unwrap();
+        assert_eq!(Some(item), set.get(id));
+        counts[ids.iter().position(|x| *x == id).unwrap()] += 1;
+    }
+    let total = set.total_weight();
+    let mut statistic = 0.0;
+    let mut cells = 0;
+    for (count, id) in counts.iter().zip(ids) {
+        let expected = set.weight(*id).unwrap() / total * DRAWS as f64;
+        if expected > 0.0 {
+            statistic += (*count as f64 - expected).powi(2) / expected;
+            cells += 1;
+        } else {
+            assert_eq!(*count, 0);
+        }
+    }
+    chi_square_p_value(statistic, cells - 1)
+}
+
+#[test]
+fn samples_follow_the_weights_through_updates() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let mut set = DynamicWeightedSet::new();
+    let ids: Vec<usize> = (0..10).map(|i| set.insert(i, (i + 1) as f64)).collect();
+    assert!(fit(&set, &ids, &mut rng) > 0.001);
+
+    set.set_weight(ids[9], 0.0);
+    set.set_weight(ids[0], 20.0);
+    assert_eq!(set.total_weight(), 20.0 + (2..=9).sum::<usize>() as f64);
+    assert!(fit(&set, &ids, &mut rng) > 0.001);
+}
+
+#[test]
+fn removed_ids_are_reused_and_never_sampled() {
+    let mut rng = MarsagliaUniRng::from_seed(9);
+    let mut set = DynamicWeightedSet::new();
+    let a = set.insert("a", 1.0);
+    let b = set.insert("b", 1.0);
+    let c = set.insert("c", 1.0);
+    assert_eq!(set.remove(b), Some("b"));
+    assert_eq!(set.remove(b), None);
+    assert_eq!(set.len(), 2);
+    for _ in 0..1000 {
+        assert_ne!(set.sample(&mut rng).unwrap().0, b);
+    }
+    assert_eq!(set.insert("d", 2.0), b);
+    assert_eq!(set.get(b), Some(&"d"));
+    assert_eq!(set.weight(c), Some(1.0));
+    assert_eq!(set.total_weight(), 4.0);
+    set.remove(a);
+    set.remove(b);
+    set.remove(c);
+    assert!(set.is_empty());
+    assert_eq!(set.sample(&mut rng), None);
+}
+
+#[test]
+fn large_sets_stay_exact_after_many_updates() {
+    let mut rng = MarsagliaUniRng::from_seed(54217137);
+    let mut set = DynamicWeightedSet::new();
+    let ids: Vec<usize> = (0..1000).map(|i| set.insert(i, 1.0)).collect();
+    for step in 0..100_000 {
+        set.set_weight(ids[step % 1000], rng.uni() as f64);
+    }
+    for id in &ids {
+        set.set_weight(*id, 0.5);
+    }
+    assert_eq!(set.total_weight(), 500.0);
+}
+
+#[test]
+#[should_panic(expected = "DynamicWeightedSet::insert: weight = -1 -- out of range")]
+fn negative_weights_panic() {
+    DynamicWeightedSet::new().insert((), -1.0);
+}
+
+#[test]
+fn cumulative_sampler_follows_batched_updates() {
+    let mut rng = MarsagliaUniRng::from_seed(1802);
+    let mut sampler = CumulativeSampler::new(&[1.0; 6]);
+    sampler.set_weights(&[(0, 4.0), (3, 0.0), (5, 2.0), (0, 3.0)]);
+    assert_eq!(
+        (0..6).map(|i| sampler.weight(i)).collect::<Vec<_>>(),
+        [3.0, 1.0, 1.0, 0.0, 1.0, 2.0]
+    );
+    assert_eq!(sampler.total_weight(), 8.0);
+
+    let mut counts = [0usize; 6];
+    for _ in 0..DRAWS {
+        counts[sampler.sample(&mut rng).unwrap()] += 1;
+    }
+    assert_eq!(counts[3], 0);
+    let statistic: f64 = counts
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i != 3)
+        .map(|(i, c)| {
+            let expected = sampler.weight(i) / 8.0 * DRAWS as f64;
+            (*c as f64 - expected).powi(2) / expected
+        })
+        .sum();
+    assert!(chi_square_p_value(statistic, 4) > 0.001);
+}
+
+#[test]
+fn batched_and_single_updates_agree() {
+    let mut rng = MarsagliaUniRng::from_seed(170);
+    let weights: Vec<f64> = (0..1000).map(|_| rng.uni() as f64).collect();
+    let mut single = CumulativeSampler::new(&weights);
+    let mut batched = single.clone();
+    let mut pushed = CumulativeSampler::new(&[]);
+    for w in &weights {
+        pushed.push(*w);
+    }
+    let updates: Vec<(usize, f64)> = (0..300)
+        .map(|_| (rng.gen_range_u32(0..1000) as usize, rng.uni() as f64))
+        .collect();
+    for (i, w) in &updates {
+        single.set_weight(*i, *w);
+        pushed.set_weight(*i, *w);
+    }
+    batched.set_weights(&updates);
+    assert_eq!(single.total_weight(), batched.total_weight());
+    assert_eq!(single.total_weight(), pushed.total_weight());
+    let (mut a, mut b) = (MarsagliaUniRng::from_seed(5), MarsagliaUniRng::from_seed(5));
+    for _ in 0..1000 {
+        assert_eq!(single.sample(&mut a), batched.sample(&mut b));
+    }
+    assert_eq!(CumulativeSampler::new(&[0.0, 0.0]).sample(&mut a), None);
+}